@@ -0,0 +1,185 @@
+use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
+use regex::Regex;
+
+const ROW: i64 = 2_000_000;
+const MAX_COORD: i64 = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Coord {
+    x: i64,
+    y: i64,
+}
+
+impl Coord {
+    fn manhattan_distance(self, other: Coord) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+pub struct Reading {
+    sensor: Coord,
+    beacon: Coord,
+}
+
+struct Diamond {
+    center: Coord,
+    radius: i64,
+}
+
+impl Diamond {
+    fn perimeter(&self) -> impl Iterator<Item = Coord> {
+        let top = Coord {
+            x: self.center.x,
+            y: self.center.y + self.radius + 1,
+        };
+        let bottom = Coord {
+            x: self.center.x,
+            y: self.center.y - self.radius - 1,
+        };
+        let left = Coord {
+            x: self.center.x - self.radius - 1,
+            y: self.center.y,
+        };
+        let right = Coord {
+            x: self.center.x + self.radius + 1,
+            y: self.center.y,
+        };
+
+        let top_right_perim = (0..=self.radius).map(move |s| Coord {
+            x: top.x + s,
+            y: top.y - s,
+        });
+
+        let top_left_perim = (0..=self.radius).map(move |s| Coord {
+            x: left.x + s,
+            y: left.y + s,
+        });
+
+        let bottom_left_perim = (0..=self.radius).map(move |s| Coord {
+            x: bottom.x - s,
+            y: bottom.y + s,
+        });
+
+        let bottom_right_perim = (0..=self.radius).map(move |s| Coord {
+            x: right.x - s,
+            y: right.y - s,
+        });
+
+        top_right_perim
+            .chain(top_left_perim)
+            .chain(bottom_left_perim)
+            .chain(bottom_right_perim)
+    }
+
+    fn contains(&self, coord: Coord) -> bool {
+        self.center.manhattan_distance(coord) <= self.radius
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Reading>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = Regex::new(
+            r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)",
+        )?;
+
+        input
+            .lines()
+            .map(|line| {
+                let caps = re
+                    .captures(line)
+                    .ok_or_else(|| anyhow!("invalid line: {line}"))?;
+
+                let sensor = Coord {
+                    x: caps[1].parse()?,
+                    y: caps[2].parse()?,
+                };
+                let beacon = Coord {
+                    x: caps[3].parse()?,
+                    y: caps[4].parse()?,
+                };
+
+                Ok(Reading { sensor, beacon })
+            })
+            .collect()
+    }
+
+    fn part1(readings: &Self::Input) -> Result<Answer> {
+        let closest_with_distance: Vec<(Coord, i64, Coord)> = readings
+            .iter()
+            .map(|r| (r.sensor, r.sensor.manhattan_distance(r.beacon), r.beacon))
+            .collect();
+
+        let mut min_x = 0;
+        let mut max_x = 0;
+
+        for (sensor, distance, _) in &closest_with_distance {
+            let reach_min_x = sensor.x - distance;
+            let reach_max_x = sensor.x + distance;
+
+            if reach_min_x < min_x {
+                min_x = reach_min_x;
+            }
+            if reach_max_x > max_x {
+                max_x = reach_max_x;
+            }
+        }
+
+        let mut blocked_positions = 0;
+        for x in min_x..=max_x {
+            let coord = Coord { x, y: ROW };
+            for (sensor, distance, beacon) in &closest_with_distance {
+                if coord == *sensor {
+                    break;
+                }
+
+                if coord == *beacon {
+                    break;
+                }
+
+                if sensor.manhattan_distance(coord) <= *distance {
+                    blocked_positions += 1;
+                    break;
+                }
+            }
+        }
+
+        Ok(blocked_positions.into())
+    }
+
+    fn part2(readings: &Self::Input) -> Result<Answer> {
+        let known_beacons: Vec<Coord> = readings.iter().map(|r| r.beacon).collect();
+
+        let diamonds: Vec<Diamond> = readings
+            .iter()
+            .map(|r| Diamond {
+                center: r.sensor,
+                radius: r.sensor.manhattan_distance(r.beacon),
+            })
+            .collect();
+
+        for diamond in &diamonds {
+            for coord in diamond.perimeter() {
+                if coord.x < 0 || coord.x > MAX_COORD || coord.y < 0 || coord.y > MAX_COORD {
+                    continue;
+                }
+
+                if known_beacons.contains(&coord) {
+                    continue;
+                }
+
+                if diamonds.iter().any(|d| d.contains(coord)) {
+                    continue;
+                }
+
+                return Ok((coord.x * 4_000_000 + coord.y).into());
+            }
+        }
+
+        Err(anyhow!("no possible location for the distress beacon"))
+    }
+}
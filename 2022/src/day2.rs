@@ -0,0 +1,154 @@
+use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy)]
+enum Outcome {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl Outcome {
+    fn score(self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    fn battle(self, other: Shape) -> Outcome {
+        match (self, other) {
+            (Shape::Rock, Shape::Paper)
+            | (Shape::Paper, Shape::Scissors)
+            | (Shape::Scissors, Shape::Rock) => Outcome::Lose,
+            (Shape::Rock, Shape::Scissors)
+            | (Shape::Paper, Shape::Rock)
+            | (Shape::Scissors, Shape::Paper) => Outcome::Win,
+            (Shape::Paper, Shape::Paper)
+            | (Shape::Rock, Shape::Rock)
+            | (Shape::Scissors, Shape::Scissors) => Outcome::Draw,
+        }
+    }
+
+    // Determine the shape we should battle this shape with in order to get the given outcome.
+    fn outcome_counter(self, outcome: Outcome) -> Shape {
+        match (self, outcome) {
+            (_, Outcome::Draw) => self,
+            (Shape::Rock, Outcome::Win) | (Shape::Scissors, Outcome::Lose) => Shape::Paper,
+            (Shape::Rock, Outcome::Lose) | (Shape::Paper, Outcome::Win) => Shape::Scissors,
+            (Shape::Paper, Outcome::Lose) | (Shape::Scissors, Outcome::Win) => Shape::Rock,
+        }
+    }
+
+    fn score(self) -> u32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    fn from_str(s: &str) -> Result<Shape> {
+        match s {
+            "A" => Ok(Shape::Rock),
+            "B" => Ok(Shape::Paper),
+            "C" => Ok(Shape::Scissors),
+            _ => Err(anyhow!("invalid shape string: {s}")),
+        }
+    }
+}
+
+/// The second column of the strategy guide, which the two parts read
+/// differently.
+#[derive(Debug, Clone, Copy)]
+pub enum Code {
+    X,
+    Y,
+    Z,
+}
+
+impl Code {
+    fn from_str(s: &str) -> Result<Code> {
+        match s {
+            "X" => Ok(Code::X),
+            "Y" => Ok(Code::Y),
+            "Z" => Ok(Code::Z),
+            _ => Err(anyhow!("invalid code string: {s}")),
+        }
+    }
+
+    fn shape(self) -> Shape {
+        match self {
+            Code::X => Shape::Rock,
+            Code::Y => Shape::Paper,
+            Code::Z => Shape::Scissors,
+        }
+    }
+
+    fn outcome(self) -> Outcome {
+        match self {
+            Code::X => Outcome::Lose,
+            Code::Y => Outcome::Draw,
+            Code::Z => Outcome::Win,
+        }
+    }
+}
+
+pub struct Round {
+    opponent: Shape,
+    code: Code,
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                let (opponent, code) = line
+                    .split_once(' ')
+                    .ok_or(anyhow!("invalid round: {line}"))?;
+                Ok(Round {
+                    opponent: Shape::from_str(opponent)?,
+                    code: Code::from_str(code)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(rounds: &Self::Input) -> Result<Answer> {
+        let mut points = 0;
+        for round in rounds {
+            let own = round.code.shape();
+            points += own.score();
+            points += own.battle(round.opponent).score();
+        }
+
+        Ok(points.into())
+    }
+
+    fn part2(rounds: &Self::Input) -> Result<Answer> {
+        let mut points = 0;
+        for round in rounds {
+            let outcome = round.code.outcome();
+            let own = round.opponent.outcome_counter(outcome);
+            points += own.score();
+            points += outcome.score();
+        }
+
+        Ok(points.into())
+    }
+}
@@ -0,0 +1,13 @@
+#![warn(clippy::pedantic)]
+#![allow(clippy::missing_errors_doc)]
+
+use aoc_common::solution::Year;
+
+mod day15;
+mod day2;
+
+pub const YEAR: Year = Year {
+    year: 2022,
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    days: &[(2, &day2::Day2), (15, &day15::Day15)],
+};
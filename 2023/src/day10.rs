@@ -1,13 +1,9 @@
-#![warn(clippy::pedantic)]
-
 use anyhow::{anyhow, Result};
-use std::{collections::HashSet, fs, str::FromStr};
-
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
+use aoc_common::{
+    geometry::{Coord, Direction8, ICoord},
+    solution::{Answer, Solution},
+};
+use std::{collections::HashSet, str::FromStr};
 
 #[derive(Debug)]
 struct Bounds {
@@ -27,104 +23,41 @@ impl Bounds {
 
     fn on_border(&self, coords: &Coord) -> bool {
         coords.x == self.x_min
-            || coords.x == self.x_min - 1
+            || coords.x == self.x_max - 1
             || coords.y == self.y_min
             || coords.y == self.y_max - 1
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
-enum Direction {
-    E,
-    W,
-    N,
-    S,
-    NE,
-    NW,
-    SE,
-    SW,
+/// Get the direction a source coordinate comes from relative to a target.
+fn direction_between(source: &Coord, target: &Coord) -> Result<Direction8> {
+    Direction8::ALL
+        .into_iter()
+        .find(|&d| target.checked_step(d) == Some(*source))
+        .ok_or(anyhow!(
+            "coords are not adjacent: {source:?} and {target:?}"
+        ))
 }
 
-impl Direction {
-    fn all() -> [Direction; 8] {
-        [
-            Self::N,
-            Self::W,
-            Self::E,
-            Self::S,
-            Self::NW,
-            Self::NE,
-            Self::SW,
-            Self::SE,
-        ]
-    }
-
-    /// Move in this direction for a specific coordinate.
-    fn take(&self, coord: &Coord) -> Coord {
-        match self {
-            Self::E => Coord {
-                x: coord.x + 1,
-                y: coord.y,
-            },
-            Self::W => Coord {
-                x: coord.x - 1,
-                y: coord.y,
-            },
-            Self::N => Coord {
-                x: coord.x,
-                y: coord.y - 1,
-            },
-            Self::S => Coord {
-                x: coord.x,
-                y: coord.y + 1,
-            },
-            Self::NE => Self::N.take(&Self::E.take(coord)),
-            Self::NW => Self::N.take(&Self::W.take(coord)),
-            Self::SE => Self::S.take(&Self::E.take(coord)),
-            Self::SW => Self::S.take(&Self::W.take(coord)),
-        }
-    }
-
-    /// Get the direction a source coordinate comes from relative to a target.
-    fn from(source: &Coord, target: &Coord) -> Result<Direction> {
-        Self::all()
-            .into_iter()
-            .find(|d| d.take(target) == *source)
-            .ok_or(anyhow!(
-                "coords are not adjacent: {:?} and {:?}",
-                source,
-                target
-            ))
-    }
-
-    /// Get the directions which are adjacent to this direction relative to the same origin.
-    fn adjacent(&self) -> HashSet<Direction> {
-        let mut result = HashSet::new();
-        let center = Coord { x: 1, y: 1 };
-        let offset = self.take(&center);
-
-        for center_dir in Self::all() {
-            for offset_dir in Self::all() {
-                if center_dir.take(&center) == offset_dir.take(&offset) {
-                    result.insert(center_dir.clone());
-                }
-            }
-        }
-
-        result
-    }
+/// Get the directions which are adjacent to a direction relative to the same origin.
+fn adjacent_directions(direction: Direction8) -> HashSet<Direction8> {
+    let offset = ICoord::default().step(direction);
+    Direction8::ALL
+        .into_iter()
+        .filter(|&d| ICoord::default().step(d).chebyshev(offset) == 1)
+        .collect()
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Connections {
-    incoming: Direction,
-    outgoing: Direction,
+    incoming: Direction8,
+    outgoing: Direction8,
 }
 
 #[derive(Debug, Clone)]
 struct Sides {
-    left: HashSet<Direction>,
-    right: HashSet<Direction>,
+    left: HashSet<Direction8>,
+    right: HashSet<Direction8>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -150,20 +83,22 @@ impl Tile {
             'F' => Ok(Self::SouthToEast),
             'S' => Ok(Self::Start),
             '.' => Ok(Self::Ground),
-            _ => Err(anyhow!("char is not a valid tile: {}", c)),
+            _ => Err(anyhow!("char is not a valid tile: {c}")),
         }
     }
 
     /// Get the endpoints of the pipes on this tile.
-    fn endpoints(&self) -> HashSet<Direction> {
+    fn endpoints(&self) -> HashSet<Direction8> {
         match self {
-            Self::NorthToSouth => HashSet::from([Direction::N, Direction::S]),
-            Self::WestToEast => HashSet::from([Direction::W, Direction::E]),
-            Self::NorthToEast => HashSet::from([Direction::N, Direction::E]),
-            Self::NorthToWest => HashSet::from([Direction::N, Direction::W]),
-            Self::SouthToWest => HashSet::from([Direction::S, Direction::W]),
-            Self::SouthToEast => HashSet::from([Direction::S, Direction::E]),
-            Self::Start => HashSet::from([Direction::N, Direction::S, Direction::W, Direction::E]),
+            Self::NorthToSouth => HashSet::from([Direction8::N, Direction8::S]),
+            Self::WestToEast => HashSet::from([Direction8::W, Direction8::E]),
+            Self::NorthToEast => HashSet::from([Direction8::N, Direction8::E]),
+            Self::NorthToWest => HashSet::from([Direction8::N, Direction8::W]),
+            Self::SouthToWest => HashSet::from([Direction8::S, Direction8::W]),
+            Self::SouthToEast => HashSet::from([Direction8::S, Direction8::E]),
+            Self::Start => {
+                HashSet::from([Direction8::N, Direction8::S, Direction8::W, Direction8::E])
+            }
             Self::Ground => HashSet::from([]),
         }
     }
@@ -178,28 +113,26 @@ impl Tile {
 
         // Get all eight surrounding tiles and filter out incoming and outgoing connections.
         let mut unmarked = HashSet::from([
-            Direction::N,
-            Direction::NE,
-            Direction::E,
-            Direction::SE,
-            Direction::S,
-            Direction::SW,
-            Direction::W,
-            Direction::NW,
+            Direction8::N,
+            Direction8::NE,
+            Direction8::E,
+            Direction8::SE,
+            Direction8::S,
+            Direction8::SW,
+            Direction8::W,
+            Direction8::NW,
         ]);
         unmarked.remove(&connections.incoming);
         unmarked.remove(&connections.outgoing);
 
         // Set the known left and right side of the incoming connection.
         let (left_in, right_in) = match connections.incoming {
-            Direction::E => Ok((Direction::SE, Direction::NE)),
-            Direction::N => Ok((Direction::NE, Direction::NW)),
-            Direction::W => Ok((Direction::NW, Direction::SW)),
-            Direction::S => Ok((Direction::SW, Direction::SE)),
+            Direction8::E => Ok((Direction8::SE, Direction8::NE)),
+            Direction8::N => Ok((Direction8::NE, Direction8::NW)),
+            Direction8::W => Ok((Direction8::NW, Direction8::SW)),
+            Direction8::S => Ok((Direction8::SW, Direction8::SE)),
             _ => Err(anyhow!(
-                "invalid incoming direction for this tile ({:?}): {:?}",
-                self,
-                connections
+                "invalid incoming direction for this tile ({self:?}): {connections:?}"
             )),
         }?;
         unmarked.remove(&left_in);
@@ -209,14 +142,12 @@ impl Tile {
 
         // Set the known left and right side of the outgoing connection.
         let (left_out, right_out) = match connections.outgoing {
-            Direction::E => Ok((Direction::NE, Direction::SE)),
-            Direction::N => Ok((Direction::NW, Direction::NE)),
-            Direction::W => Ok((Direction::SW, Direction::NW)),
-            Direction::S => Ok((Direction::SE, Direction::SW)),
+            Direction8::E => Ok((Direction8::NE, Direction8::SE)),
+            Direction8::N => Ok((Direction8::NW, Direction8::NE)),
+            Direction8::W => Ok((Direction8::SW, Direction8::NW)),
+            Direction8::S => Ok((Direction8::SE, Direction8::SW)),
             _ => Err(anyhow!(
-                "invalid outgoing direction for this tile ({:?}): {:?}",
-                self,
-                connections
+                "invalid outgoing direction for this tile ({self:?}): {connections:?}"
             )),
         }?;
         unmarked.remove(&left_out);
@@ -230,7 +161,7 @@ impl Tile {
                 .left
                 .clone()
                 .into_iter()
-                .flat_map(|d| d.adjacent())
+                .flat_map(adjacent_directions)
                 .for_each(|adj| {
                     if adj != connections.incoming && adj != connections.outgoing {
                         unmarked.remove(&adj);
@@ -241,7 +172,7 @@ impl Tile {
                 .right
                 .clone()
                 .into_iter()
-                .flat_map(|d| d.adjacent())
+                .flat_map(adjacent_directions)
                 .for_each(|adj| {
                     if adj != connections.incoming && adj != connections.outgoing {
                         unmarked.remove(&adj);
@@ -255,7 +186,7 @@ impl Tile {
 }
 
 #[derive(Debug)]
-struct Grid {
+pub struct Grid {
     start: Coord,
     tiles: Vec<Vec<Tile>>,
     bounds: Bounds,
@@ -268,7 +199,7 @@ impl Grid {
         self.tiles[coord.y][coord.x]
             .endpoints()
             .into_iter()
-            .map(|d| d.take(coord))
+            .filter_map(|d| coord.checked_step(d))
             .filter(|c| self.bounds.within(c))
             .collect()
     }
@@ -284,42 +215,34 @@ impl Grid {
 
     /// Find a loop/cycle of pipes starting from the grid's starting point.
     fn find_cycle(&self) -> Result<Vec<Coord>> {
-        fn depth_first_search(
-            grid: &Grid,
-            previous: &Option<Coord>,
-            current: &Coord,
-            path: &mut Vec<Coord>,
-        ) -> bool {
-            path.push(current.clone());
-            for next in grid.matching_adjacent(current) {
-                if let Some(previous) = &previous {
-                    if next == *previous {
-                        continue; // circling back of not valid
-                    }
-                }
-                if next == grid.start {
-                    return true; // we have come back to the start and are finished
-                }
-                if depth_first_search(grid, &Some(current.clone()), &next, path) {
-                    return true; // if this branch succeeded down the line, we are finished
-                }
+        // A depth-first search, with the untried branches of every tile on the
+        // path kept on a stack of their own.
+        let mut path = vec![self.start];
+        let mut branches: Vec<Vec<Coord>> =
+            vec![self.matching_adjacent(&self.start).into_iter().collect()];
+        while let Some(branch) = branches.last_mut() {
+            let Some(next) = branch.pop() else {
+                // none of the branches succeeded, thus remove the element again
+                branches.pop();
+                path.pop();
+                continue;
+            };
+            if path.len() > 1 && next == path[path.len() - 2] {
+                continue; // circling back of not valid
             }
-            path.pop(); // none of the branches succeeded, thus remove the element again
-            false
-        }
-
-        let mut result = Vec::new();
-        if depth_first_search(self, &None, &self.start, &mut result) {
-            Ok(result)
-        } else {
-            Err(anyhow!("no cycle could be found in grid: {:?}", self))
+            if next == self.start {
+                return Ok(path); // we have come back to the start and are finished
+            }
+            branches.push(self.matching_adjacent(&next).into_iter().collect());
+            path.push(next);
         }
+        Err(anyhow!("no cycle could be found in grid: {self:?}"))
     }
 
     /// Get the tiles enclosed by a cycle. Marks the bordering tiles on the inside
     /// and outside and flood-fills the desired enclosed space.
     fn enclosed_by_cycle(&self, cycle: &[Coord]) -> Result<HashSet<Coord>> {
-        let cycle_coords: HashSet<Coord> = cycle.iter().cloned().collect();
+        let cycle_coords: HashSet<Coord> = cycle.iter().copied().collect();
         let mut left: HashSet<Coord> = HashSet::new();
         let mut right: HashSet<Coord> = HashSet::new();
 
@@ -337,21 +260,23 @@ impl Grid {
             };
 
             let connections = Connections {
-                incoming: Direction::from(predecessor, current)?,
-                outgoing: Direction::from(successor, current)?,
+                incoming: direction_between(predecessor, current)?,
+                outgoing: direction_between(successor, current)?,
             };
 
             let sides = self.tiles[current.y][current.x].surrounding_sides(&connections)?;
             for l in sides.left {
-                let target = l.take(current);
-                if self.bounds.within(&target) && !cycle_coords.contains(&target) {
-                    left.insert(target);
+                if let Some(target) = current.checked_step(l) {
+                    if self.bounds.within(&target) && !cycle_coords.contains(&target) {
+                        left.insert(target);
+                    }
                 }
             }
             for r in sides.right {
-                let target = r.take(current);
-                if self.bounds.within(&target) && !cycle_coords.contains(&target) {
-                    right.insert(target);
+                if let Some(target) = current.checked_step(r) {
+                    if self.bounds.within(&target) && !cycle_coords.contains(&target) {
+                        right.insert(target);
+                    }
                 }
             }
         }
@@ -373,9 +298,8 @@ impl Grid {
         let mut fresh = fill.clone();
         loop {
             let mut next_fresh = HashSet::new();
-            for coord in fresh {
-                for dir in Direction::all() {
-                    let neighbour = dir.take(&coord);
+            for coord in &fresh {
+                for neighbour in coord.neighbours8() {
                     if self.bounds.within(&neighbour)
                         && !fill.contains(&neighbour)
                         && !border.contains(&neighbour)
@@ -384,29 +308,13 @@ impl Grid {
                     }
                 }
             }
-            fresh = next_fresh.clone();
+            fresh.clone_from(&next_fresh);
             if next_fresh.is_empty() {
                 break;
             }
             fill.extend(next_fresh);
         }
     }
-
-    /// Print a cycle along with its enclosed area to standard output.
-    fn print_enclosed(&self, cycle: &Vec<Coord>, enclosed: &HashSet<Coord>) {
-        let mut result: Vec<Vec<char>> = (self.bounds.y_min..self.bounds.y_max)
-            .map(|_| vec!['.'; self.bounds.x_max - self.bounds.x_min])
-            .collect();
-        for coord in cycle {
-            result[coord.y][coord.x] = 'x';
-        }
-        for coord in enclosed {
-            result[coord.y][coord.x] = 'I';
-        }
-        for row in result {
-            println!("{}", row.iter().collect::<String>());
-        }
-    }
 }
 
 impl FromStr for Grid {
@@ -424,7 +332,7 @@ impl FromStr for Grid {
         let start_row = tiles
             .iter()
             .position(|row| row.contains(&Tile::Start))
-            .ok_or(anyhow!("no starting point found in grid: {}", s))?;
+            .ok_or(anyhow!("no starting point found in grid: {s}"))?;
         let start_col = tiles[start_row]
             .iter()
             .position(|t| *t == Tile::Start)
@@ -445,12 +353,23 @@ impl FromStr for Grid {
     }
 }
 
-fn main() -> Result<()> {
-    let file = fs::read_to_string("input.txt")?;
-    let grid = Grid::from_str(&file)?;
-    let cycle = grid.find_cycle()?;
-    let enclosed = grid.enclosed_by_cycle(&cycle)?;
-    grid.print_enclosed(&cycle, &enclosed);
-    println!("{}", enclosed.len());
-    Ok(())
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::from_str(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        let cycle = grid.find_cycle()?;
+        Ok((cycle.len() / 2).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        let cycle = grid.find_cycle()?;
+        let enclosed = grid.enclosed_by_cycle(&cycle)?;
+        Ok(enclosed.len().into())
+    }
 }
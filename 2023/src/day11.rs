@@ -1,23 +1,19 @@
-#![warn(clippy::pedantic)]
-
 use anyhow::Result;
-use std::{collections::HashSet, fs, str::FromStr};
-
-#[derive(Debug)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
+use aoc_common::{
+    geometry::Coord,
+    solution::{Answer, Solution},
+};
+use std::{collections::HashSet, str::FromStr};
 
-#[derive(Debug)]
-struct Space {
+#[derive(Debug, Clone)]
+pub struct Space {
     dimensions: Coord,
     galaxies: Vec<Coord>,
 }
 
 impl Space {
-    fn expand(&mut self) {
-        let multiplier = 1_000_000;
+    /// Replace every empty row and column by `multiplier` of them.
+    fn expand(&mut self, multiplier: usize) {
         let mut empty_rows = HashSet::new();
         let mut empty_cols = HashSet::new();
 
@@ -32,11 +28,6 @@ impl Space {
             }
         }
 
-        self.dimensions = Coord {
-            x: self.dimensions.x + (multiplier - 1) * empty_rows.len(),
-            y: self.dimensions.y + (multiplier - 1) * empty_cols.len(),
-        };
-
         for galaxy in &mut self.galaxies {
             let y_expand =
                 empty_rows.iter().filter(|row| **row < galaxy.y).count() * (multiplier - 1);
@@ -48,6 +39,16 @@ impl Space {
             };
         }
     }
+
+    fn sum_of_distances(&self) -> usize {
+        let mut result = 0;
+        for i in 0..self.galaxies.len() {
+            for j in i + 1..self.galaxies.len() {
+                result += self.galaxies[i].manhattan(self.galaxies[j]);
+            }
+        }
+        result
+    }
 }
 
 impl FromStr for Space {
@@ -72,30 +73,24 @@ impl FromStr for Space {
     }
 }
 
-fn manhattan_distance(point1: &Coord, point2: &Coord) -> usize {
-    (if point1.x > point2.x {
-        point1.x - point2.x
-    } else {
-        point2.x - point1.x
-    }) + (if point1.y > point2.y {
-        point1.y - point2.y
-    } else {
-        point2.y - point1.y
-    })
-}
+pub struct Day11;
 
-fn main() -> Result<()> {
-    let file = fs::read_to_string("input.txt")?;
-    let mut space = Space::from_str(&file)?;
-    space.expand();
+impl Solution for Day11 {
+    type Input = Space;
 
-    let mut result = 0;
-    for i in 0..space.galaxies.len() {
-        for j in i + 1..space.galaxies.len() {
-            result += manhattan_distance(&space.galaxies[i], &space.galaxies[j]);
-        }
+    fn parse(input: &str) -> Result<Self::Input> {
+        Space::from_str(input)
     }
 
-    println!("{result}");
-    Ok(())
+    fn part1(space: &Self::Input) -> Result<Answer> {
+        let mut space = space.clone();
+        space.expand(2);
+        Ok(space.sum_of_distances().into())
+    }
+
+    fn part2(space: &Self::Input) -> Result<Answer> {
+        let mut space = space.clone();
+        space.expand(1_000_000);
+        Ok(space.sum_of_distances().into())
+    }
 }
@@ -1,12 +1,8 @@
-#![warn(clippy::pedantic)]
-#![allow(clippy::bool_to_int_with_if)]
-
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
 use hashbrown::HashMap;
-use itertools::Itertools;
-use std::{fs, iter};
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Spring {
     Operational,
     Damaged,
@@ -14,23 +10,38 @@ enum Spring {
 }
 
 impl Spring {
-    fn from_char(s: char) -> Self {
+    fn from_char(s: char) -> Result<Self> {
         match s {
-            '.' => Self::Operational,
-            '#' => Self::Damaged,
-            '?' => Self::Unknown,
-            _ => panic!("unexpected spring char: {s}"),
+            '.' => Ok(Self::Operational),
+            '#' => Ok(Self::Damaged),
+            '?' => Ok(Self::Unknown),
+            _ => Err(anyhow!("unexpected spring char: {s}")),
         }
     }
 }
 
 #[derive(Debug)]
-struct Row {
+pub struct Row {
     springs: Vec<Spring>,
     group_sizes: Vec<u64>,
 }
 
 impl Row {
+    /// Repeat the row `copies` times, with unknown springs in between.
+    fn unfold(&self, copies: usize) -> Self {
+        let mut springs = Vec::new();
+        for i in 0..copies {
+            if i > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend_from_slice(&self.springs);
+        }
+        Self {
+            springs,
+            group_sizes: self.group_sizes.repeat(copies),
+        }
+    }
+
     fn count_arrangements(&self) -> u64 {
         #[derive(Debug, PartialEq, Eq, Hash)]
         struct Params<'a> {
@@ -39,6 +50,7 @@ impl Row {
             group_head_depth: u64,
         }
 
+        #[allow(clippy::bool_to_int_with_if)]
         fn count_recursive<'a>(p: Params<'a>, cache: &mut HashMap<Params<'a>, u64>) -> u64 {
             // assert!(p.group_sizes.len() > 0 || p.group_head_depth == 0);
             // assert!(p.group_sizes.len() == 0 || p.group_head_depth <= p.group_sizes[0]);
@@ -126,34 +138,43 @@ impl Row {
     }
 }
 
-fn main() -> Result<()> {
-    let copies = 5;
-    let file = fs::read_to_string("input.txt")?;
-    let result = file
-        .lines()
-        .map(|line| {
-            let (springs_raw, group_sizes_raw) = line.split_once(' ').unwrap();
-            let springs: Vec<Spring> = iter::repeat(springs_raw)
-                .take(copies)
-                .collect_vec()
-                .join("?")
-                .chars()
-                .map(Spring::from_char)
-                .collect();
-            let group_sizes: Vec<u64> = iter::repeat(group_sizes_raw)
-                .take(copies)
-                .collect_vec()
-                .join(",")
-                .split(',')
-                .map(|s| s.parse().unwrap())
-                .collect();
-            Row {
-                springs,
-                group_sizes,
-            }
-        })
-        .map(|r| r.count_arrangements())
-        .sum::<u64>();
-    println!("{result}");
-    Ok(())
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Row>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                let (springs_raw, group_sizes_raw) =
+                    line.split_once(' ').ok_or(anyhow!("invalid row: {line}"))?;
+                let springs = springs_raw
+                    .chars()
+                    .map(Spring::from_char)
+                    .collect::<Result<Vec<Spring>>>()?;
+                let group_sizes = group_sizes_raw
+                    .split(',')
+                    .map(str::parse)
+                    .collect::<Result<Vec<u64>, _>>()?;
+                Ok(Row {
+                    springs,
+                    group_sizes,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(rows: &Self::Input) -> Result<Answer> {
+        let result = rows.iter().map(Row::count_arrangements).sum::<u64>();
+        Ok(result.into())
+    }
+
+    fn part2(rows: &Self::Input) -> Result<Answer> {
+        let result = rows
+            .iter()
+            .map(|r| r.unfold(5).count_arrangements())
+            .sum::<u64>();
+        Ok(result.into())
+    }
 }
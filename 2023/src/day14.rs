@@ -0,0 +1,93 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    cycle::nth,
+    geometry::{Coord, Direction},
+    grid::Grid,
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Cell {
+    CubeRock,
+    RoundRock,
+    Ash,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Platform(Grid<Cell>);
+
+impl Platform {
+    /// Roll every round rock as far as it goes towards `direction`.
+    fn tilt(&mut self, direction: Direction) {
+        let grid = &mut self.0;
+        // Move the rocks nearest to the edge first, so that the others stop
+        // against them.
+        let mut coords: Vec<Coord> = grid.coords().collect();
+        if matches!(direction, Direction::Down | Direction::Right) {
+            coords.reverse();
+        }
+
+        for coord in coords {
+            if grid[coord] != Cell::RoundRock {
+                continue;
+            }
+            let mut target = coord;
+            while let Some(next) = grid
+                .step(target, direction)
+                .filter(|&next| grid[next] == Cell::Ash)
+            {
+                target = next;
+            }
+            grid[coord] = Cell::Ash;
+            grid[target] = Cell::RoundRock;
+        }
+    }
+
+    /// Tilt north, west, south and east in turn.
+    fn spin_cycle(&self) -> Self {
+        let mut result = self.clone();
+        for direction in [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ] {
+            result.tilt(direction);
+        }
+        result
+    }
+
+    fn north_load(&self) -> usize {
+        self.0
+            .positions(|&cell| cell == Cell::RoundRock)
+            .map(|coord| self.0.height() - coord.y)
+            .sum()
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Platform;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse(input, |c| match c {
+            '#' => Ok(Cell::CubeRock),
+            'O' => Ok(Cell::RoundRock),
+            '.' => Ok(Cell::Ash),
+            _ => Err(anyhow!("invalid character in platform: {c}")),
+        })?;
+        Ok(Platform(grid))
+    }
+
+    fn part1(platform: &Self::Input) -> Result<Answer> {
+        let mut platform = platform.clone();
+        platform.tilt(Direction::Up);
+        Ok(platform.north_load().into())
+    }
+
+    fn part2(platform: &Self::Input) -> Result<Answer> {
+        let platform = nth(platform.clone(), Platform::spin_cycle, 1_000_000_000);
+        Ok(platform.north_load().into())
+    }
+}
@@ -1,8 +1,7 @@
-#![warn(clippy::pedantic)]
-
 use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
 use regex::Regex;
-use std::{fs, str::FromStr, vec};
+use std::{str::FromStr, vec};
 
 #[derive(Debug)]
 enum Operation {
@@ -11,7 +10,8 @@ enum Operation {
 }
 
 #[derive(Debug)]
-struct Step {
+pub struct Step {
+    text: String,
     label: String,
     operation: Operation,
 }
@@ -21,17 +21,19 @@ impl FromStr for Step {
 
     fn from_str(s: &str) -> Result<Self> {
         let re = Regex::new(r"([ -~]+)(?:-|(?:=([0-9]+)))")?;
-        let caps = re.captures(s).ok_or(anyhow!("invalid step: {}", s))?;
+        let caps = re.captures(s).ok_or(anyhow!("invalid step: {s}"))?;
 
         let label = caps.get(1).unwrap().as_str().to_owned();
         if let Some(focal_length_raw) = caps.get(2) {
             let focal_length = focal_length_raw.as_str().parse()?;
             Ok(Self {
+                text: s.to_owned(),
                 label,
                 operation: Operation::Insert(focal_length),
             })
         } else {
             Ok(Self {
+                text: s.to_owned(),
                 label,
                 operation: Operation::Remove,
             })
@@ -77,7 +79,7 @@ impl Boxes {
     }
 
     fn apply(&mut self, step: &Step) {
-        let hash = label_hash(&step.label);
+        let hash = hash(&step.label);
         let b = &mut self.inner[hash];
         match step.operation {
             Operation::Remove => b.remove_lens(&step.label),
@@ -101,7 +103,7 @@ impl Boxes {
     }
 }
 
-fn label_hash(s: &str) -> usize {
+fn hash(s: &str) -> usize {
     let mut value = 0;
     for char in s.chars() {
         let ascii = char as u8;
@@ -112,16 +114,26 @@ fn label_hash(s: &str) -> usize {
     value
 }
 
-fn main() -> Result<()> {
-    let file = fs::read_to_string("input.txt")?;
-    let mut boxes = Boxes::new();
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.trim_end().split(',').map(Step::from_str).collect()
+    }
 
-    for step in file.split(',').map(Step::from_str) {
-        let step = step?;
-        boxes.apply(&step);
+    fn part1(steps: &Self::Input) -> Result<Answer> {
+        let result: usize = steps.iter().map(|step| hash(&step.text)).sum();
+        Ok(result.into())
     }
 
-    let result = boxes.focusing_power();
-    println!("{result}");
-    Ok(())
+    fn part2(steps: &Self::Input) -> Result<Answer> {
+        let mut boxes = Boxes::new();
+        for step in steps {
+            boxes.apply(step);
+        }
+
+        Ok(boxes.focusing_power().into())
+    }
 }
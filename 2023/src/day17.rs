@@ -0,0 +1,153 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    geometry::{Coord, Direction},
+    search::{dial, DenseStateSpace, StateSpace},
+    solution::{Answer, Solution},
+};
+use std::ops::Range;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Chain {
+    direction: Direction,
+    length: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Vertex {
+    coord: Coord,
+    chain: Chain,
+}
+
+pub struct Grid {
+    inner: Vec<Vec<u32>>,
+    x_max: usize,
+    y_max: usize,
+}
+
+impl Grid {
+    fn new(inner: Vec<Vec<u32>>) -> Self {
+        let x_max = inner[0].len() - 1;
+        let y_max = inner.len() - 1;
+        Self {
+            inner,
+            x_max,
+            y_max,
+        }
+    }
+
+    fn least_heat_loss(&self, allowed_chains: Range<u32>) -> Result<u32> {
+        let start = Coord { x: 0, y: 0 };
+        let starts = Direction::ALL.map(|direction| Vertex {
+            coord: start,
+            chain: Chain {
+                direction,
+                length: 0,
+            },
+        });
+        let crucible = Crucible {
+            grid: self,
+            allowed_chains,
+        };
+        let path = dial(&crucible, starts).ok_or(anyhow!("no path to the factory"))?;
+        Ok(path.cost)
+    }
+}
+
+/// A crucible moving over the grid, which goes straight for a number of blocks
+/// in `allowed_chains` before it turns.
+struct Crucible<'a> {
+    grid: &'a Grid,
+    allowed_chains: Range<u32>,
+}
+
+impl StateSpace for Crucible<'_> {
+    type State = Vertex;
+    type Cost = u32;
+
+    fn successors(&self, vertex: &Vertex) -> impl Iterator<Item = (Vertex, u32)> {
+        let chain = vertex.chain;
+        Direction::ALL
+            .into_iter()
+            .filter(move |&d| {
+                (d != chain.direction.reverse())
+                    && ((d == chain.direction && chain.length < self.allowed_chains.end - 1)
+                        || (d != chain.direction && chain.length >= self.allowed_chains.start))
+            })
+            .filter_map(move |d| {
+                let coord =
+                    vertex
+                        .coord
+                        .step_within(d, self.grid.x_max + 1, self.grid.y_max + 1)?;
+                let length = if d == chain.direction {
+                    chain.length + 1
+                } else {
+                    1
+                };
+                let next = Vertex {
+                    coord,
+                    chain: Chain {
+                        direction: d,
+                        length,
+                    },
+                };
+                Some((next, self.grid.inner[coord.y][coord.x]))
+            })
+    }
+
+    fn is_goal(&self, vertex: &Vertex) -> bool {
+        vertex.coord.x == self.grid.x_max && vertex.coord.y == self.grid.y_max
+    }
+
+    fn heuristic(&self, vertex: &Vertex) -> u32 {
+        let end = Coord {
+            x: self.grid.x_max,
+            y: self.grid.y_max,
+        };
+        u32::try_from(vertex.coord.manhattan(end)).unwrap_or(0)
+    }
+}
+
+impl DenseStateSpace for Crucible<'_> {
+    fn state_count(&self) -> usize {
+        let chains = self.allowed_chains.end as usize;
+        (self.grid.x_max + 1) * (self.grid.y_max + 1) * 4 * chains
+    }
+
+    fn index(&self, vertex: &Vertex) -> usize {
+        let Vertex { coord, chain } = vertex;
+        let cell = coord.y * (self.grid.x_max + 1) + coord.x;
+        (cell * 4 + chain.direction.index()) * self.allowed_chains.end as usize
+            + chain.length as usize
+    }
+
+    fn max_step(&self) -> u32 {
+        9
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| c.to_digit(10).ok_or(anyhow!("invalid digit")))
+                    .collect::<Result<Vec<u32>>>()
+            })
+            .collect::<Result<Vec<Vec<u32>>>>()?;
+
+        Ok(Grid::new(grid))
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(grid.least_heat_loss(1..4)?.into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        Ok(grid.least_heat_loss(4..11)?.into())
+    }
+}
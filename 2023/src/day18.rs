@@ -0,0 +1,290 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    geometry::{Direction, ICoord},
+    solution::{Answer, Solution},
+};
+use regex::Regex;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+struct Bounds {
+    x_min: isize,
+    x_max: isize,
+    y_min: isize,
+    y_max: isize,
+}
+
+impl Bounds {
+    fn from_coords(coords: &Vec<ICoord>) -> Self {
+        let mut bounds = Bounds {
+            x_min: 0,
+            x_max: 0,
+            y_min: 0,
+            y_max: 0,
+        };
+
+        for coord in coords {
+            if coord.x < bounds.x_min {
+                bounds.x_min = coord.x;
+            }
+            if coord.x > bounds.x_max {
+                bounds.x_max = coord.x;
+            }
+            if coord.y < bounds.y_min {
+                bounds.y_min = coord.y;
+            }
+            if coord.y > bounds.y_max {
+                bounds.y_max = coord.y;
+            }
+        }
+
+        bounds
+    }
+
+    fn within(&self, coord: &ICoord) -> bool {
+        coord.x >= self.x_min
+            && coord.x <= self.x_max
+            && coord.y >= self.y_min
+            && coord.y <= self.y_max
+    }
+
+    fn on_border(&self, coord: &ICoord) -> bool {
+        coord.x == self.x_min
+            || coord.x == self.x_max
+            || coord.y == self.y_min
+            || coord.y == self.y_max
+    }
+}
+
+fn direction_between(source: ICoord, target: ICoord) -> Direction {
+    Direction::ALL
+        .into_iter()
+        .find(|&d| target.step(d) == source)
+        .unwrap()
+}
+
+fn direction_from_digit(d: u32) -> Result<Direction> {
+    match d {
+        0 => Ok(Direction::Right),
+        1 => Ok(Direction::Down),
+        2 => Ok(Direction::Left),
+        3 => Ok(Direction::Up),
+        _ => Err(anyhow!("invalid direction digit: {d}")),
+    }
+}
+
+#[derive(Debug)]
+struct Instruction {
+    direction: Direction,
+    meters: u32,
+}
+
+/// A line of the dig plan, which holds the instruction for part 1 and, encoded
+/// in the color, the one for part 2.
+#[derive(Debug)]
+pub struct Step {
+    instruction: Instruction,
+    color_instruction: Instruction,
+}
+
+impl Step {
+    fn from_line(re: &Regex, s: &str) -> Result<Self> {
+        let captures = re.captures(s).ok_or(anyhow!("invalid step: {s}"))?;
+
+        let direction = captures[1]
+            .chars()
+            .next()
+            .and_then(Direction::from_char)
+            .ok_or(anyhow!("invalid direction: {}", &captures[1]))?;
+        let meters = captures[2].parse()?;
+
+        let color_meters = u32::from_str_radix(&captures[3], 16)?;
+        let color_direction = direction_from_digit(u32::from_str_radix(&captures[4], 16)?)?;
+
+        Ok(Self {
+            instruction: Instruction { direction, meters },
+            color_instruction: Instruction {
+                direction: color_direction,
+                meters: color_meters,
+            },
+        })
+    }
+}
+
+fn dig<'a>(instructions: impl Iterator<Item = &'a Instruction>) -> Vec<ICoord> {
+    let mut border = Vec::new();
+    let mut current = ICoord::default();
+    for instruction in instructions {
+        for _ in 0..instruction.meters {
+            current = current.step(instruction.direction);
+            border.push(current);
+        }
+    }
+    border
+}
+
+fn enclosed_by_cycle(cycle: &Vec<ICoord>) -> HashSet<ICoord> {
+    let bounds = Bounds::from_coords(cycle);
+    let border: HashSet<ICoord> = cycle.iter().copied().collect();
+    let mut left: HashSet<ICoord> = HashSet::new();
+    let mut right: HashSet<ICoord> = HashSet::new();
+
+    for i in 0..cycle.len() {
+        let predecessor = if i > 0 {
+            &cycle[i - 1]
+        } else {
+            cycle.last().unwrap()
+        };
+        let current = &cycle[i];
+        let successor = if i < cycle.len() - 1 {
+            &cycle[i + 1]
+        } else {
+            cycle.first().unwrap()
+        };
+
+        let (left_side, right_side) = perimeter_sides(
+            direction_between(*predecessor, *current),
+            direction_between(*successor, *current),
+        );
+        for l in left_side {
+            let target = current.step(l);
+            if bounds.within(&target) && !border.contains(&target) {
+                left.insert(target);
+            }
+        }
+        for r in right_side {
+            let target = current.step(r);
+            if bounds.within(&target) && !border.contains(&target) {
+                right.insert(target);
+            }
+        }
+    }
+
+    flood_fill(&mut right, &border, &bounds);
+    flood_fill(&mut left, &border, &bounds);
+
+    if left.iter().any(|t| bounds.on_border(t)) {
+        assert!(!right.iter().any(|t| bounds.on_border(t)));
+        right
+    } else {
+        assert!(right.iter().any(|t| bounds.on_border(t)));
+        left
+    }
+}
+
+fn perimeter_sides(
+    incoming: Direction,
+    outgoing: Direction,
+) -> (HashSet<Direction>, HashSet<Direction>) {
+    let mut left = HashSet::new();
+    let mut right = HashSet::new();
+
+    match incoming {
+        Direction::Up => {
+            left.insert(Direction::Right);
+            right.insert(Direction::Left);
+        }
+        Direction::Down => {
+            left.insert(Direction::Left);
+            right.insert(Direction::Right);
+        }
+        Direction::Left => {
+            left.insert(Direction::Up);
+            right.insert(Direction::Down);
+        }
+        Direction::Right => {
+            left.insert(Direction::Down);
+            right.insert(Direction::Up);
+        }
+    }
+
+    match outgoing {
+        Direction::Up => {
+            left.insert(Direction::Left);
+            right.insert(Direction::Right);
+        }
+        Direction::Down => {
+            left.insert(Direction::Right);
+            right.insert(Direction::Left);
+        }
+        Direction::Left => {
+            left.insert(Direction::Down);
+            right.insert(Direction::Up);
+        }
+        Direction::Right => {
+            left.insert(Direction::Up);
+            right.insert(Direction::Down);
+        }
+    }
+
+    left.retain(|&d| d != incoming && d != outgoing);
+    right.retain(|&d| d != incoming && d != outgoing);
+    (left, right)
+}
+
+fn flood_fill(seeds: &mut HashSet<ICoord>, border: &HashSet<ICoord>, bounds: &Bounds) {
+    let mut fresh = seeds.clone();
+    loop {
+        let mut next_fresh = HashSet::new();
+        for coord in &fresh {
+            for dir in Direction::ALL {
+                let neighbour = coord.step(dir);
+                if bounds.within(&neighbour)
+                    && !seeds.contains(&neighbour)
+                    && !border.contains(&neighbour)
+                {
+                    next_fresh.insert(neighbour);
+                }
+            }
+        }
+        fresh.clone_from(&next_fresh);
+        if next_fresh.is_empty() {
+            break;
+        }
+        seeds.extend(next_fresh);
+    }
+}
+
+fn compute_surface(border: &[ICoord]) -> isize {
+    let first = border.first().unwrap();
+    let last = border.last().unwrap();
+
+    // Compute surface using Shoelace formula, pairing the terms so the running
+    // sum stays small.
+    let mut result = last.x * first.y - last.y * first.x;
+    for pair in border.windows(2) {
+        result += pair[0].x * pair[1].y - pair[0].y * pair[1].x;
+    }
+    result = result.abs();
+    result /= 2;
+
+    // Correct for half the border not being included.
+    result += isize::try_from(border.len()).unwrap() / 2 + 1;
+
+    result
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = Regex::new(r"^([UDLR]) (\d+) \(#([0-9a-f]{5})([0-9a-f])\)$")?;
+        input
+            .lines()
+            .map(|line| Step::from_line(&re, line))
+            .collect()
+    }
+
+    fn part1(steps: &Self::Input) -> Result<Answer> {
+        let cycle = dig(steps.iter().map(|s| &s.instruction));
+        let enclosed = enclosed_by_cycle(&cycle);
+        Ok((cycle.len() + enclosed.len()).into())
+    }
+
+    fn part2(steps: &Self::Input) -> Result<Answer> {
+        let border = dig(steps.iter().map(|s| &s.color_instruction));
+        Ok(compute_surface(&border).into())
+    }
+}
@@ -0,0 +1,313 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    boxes::Cuboid,
+    dot::Dot,
+    solution::{Answer, Solution},
+};
+use regex::Regex;
+use std::{collections::HashMap, fmt};
+
+/// The ratings of all parts, from 1 to 4000 in each category.
+const RATINGS: Cuboid<4> = Cuboid {
+    min: [1; 4],
+    max: [4001; 4],
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Category {
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "x" => Ok(Self::X),
+            "m" => Ok(Self::M),
+            "a" => Ok(Self::A),
+            "s" => Ok(Self::S),
+            _ => Err(anyhow!("invalid category: {s}")),
+        }
+    }
+
+    /// The axis of the category in a cuboid of ratings.
+    fn axis(self) -> usize {
+        match self {
+            Self::X => 0,
+            Self::M => 1,
+            Self::A => 2,
+            Self::S => 3,
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::X => write!(f, "x"),
+            Self::M => write!(f, "m"),
+            Self::A => write!(f, "a"),
+            Self::S => write!(f, "s"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Condition {
+    category: Category,
+    greater_than: bool, // if false, smaller than
+    value: i64,
+}
+
+impl Condition {
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some((category_raw, value_raw)) = s.split_once('>') {
+            Ok(Self {
+                category: Category::from_str(category_raw)?,
+                greater_than: true,
+                value: value_raw.parse()?,
+            })
+        } else {
+            let (category_raw, value_raw) =
+                s.split_once('<').ok_or(anyhow!("invalid condition: {s}"))?;
+            Ok(Self {
+                category: Category::from_str(category_raw)?,
+                greater_than: false,
+                value: value_raw.parse()?,
+            })
+        }
+    }
+
+    fn holds(&self, ratings: &Ratings) -> bool {
+        if let Some(value) = ratings.0.get(&self.category) {
+            if self.greater_than {
+                *value > self.value
+            } else {
+                *value < self.value
+            }
+        } else {
+            false
+        }
+    }
+
+    /// Split ratings into those that meet the condition and those that do
+    /// not.
+    fn split(&self, ratings: &Cuboid<4>) -> (Option<Cuboid<4>>, Option<Cuboid<4>>) {
+        let axis = self.category.axis();
+        if self.greater_than {
+            let (below, above) = ratings.split(axis, self.value + 1);
+            (above, below)
+        } else {
+            ratings.split(axis, self.value)
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operator = if self.greater_than { '>' } else { '<' };
+        write!(f, "{}{operator}{}", self.category, self.value)
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    condition: Option<Condition>,
+    target: String,
+}
+
+impl Rule {
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some((condition_raw, target)) = s.split_once(':') {
+            Ok(Rule {
+                condition: Some(Condition::from_str(condition_raw)?),
+                target: target.to_owned(),
+            })
+        } else {
+            Ok(Rule {
+                condition: None,
+                target: s.to_owned(),
+            })
+        }
+    }
+
+    fn holds(&self, ratings: &Ratings) -> bool {
+        self.condition.as_ref().is_none_or(|c| c.holds(ratings))
+    }
+}
+
+#[derive(Debug)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    fn from_line(re: &Regex, s: &str) -> Result<Self> {
+        let captures = re.captures(s).ok_or(anyhow!("invalid workflow: {s}"))?;
+        let name = captures[1].to_owned();
+        let rules = captures[2]
+            .split(',')
+            .map(Rule::from_str)
+            .collect::<Result<_>>()?;
+
+        Ok(Self { name, rules })
+    }
+
+    fn apply(&self, ratings: &Ratings) -> Result<&str> {
+        self.rules
+            .iter()
+            .find(|rule| rule.holds(ratings))
+            .map(|rule| rule.target.as_str())
+            .ok_or(anyhow!("no rules of workflow {} hold", self.name))
+    }
+}
+
+#[derive(Debug)]
+struct Workflows {
+    inner: HashMap<String, Workflow>,
+}
+
+impl Workflows {
+    fn get(&self, name: &str) -> Result<&Workflow> {
+        self.inner
+            .get(name)
+            .ok_or(anyhow!("unknown workflow: {name}"))
+    }
+
+    fn accepts(&self, ratings: &Ratings) -> Result<bool> {
+        let mut workflow_name = "in";
+        while workflow_name != "A" && workflow_name != "R" {
+            workflow_name = self.get(workflow_name)?.apply(ratings)?;
+        }
+        Ok(workflow_name == "A")
+    }
+
+    fn acceptance_combinations(&self, start: &str, ratings: Cuboid<4>) -> Result<u128> {
+        if start == "A" {
+            return Ok(ratings.volume());
+        }
+        if start == "R" {
+            return Ok(0);
+        }
+
+        let mut result = 0;
+        let mut rest = Some(ratings);
+        for rule in &self.get(start)?.rules {
+            let Some(ratings) = rest else {
+                return Ok(result);
+            };
+            if let Some(condition) = &rule.condition {
+                let (with, without) = condition.split(&ratings);
+                if let Some(with) = with {
+                    result += self.acceptance_combinations(&rule.target, with)?;
+                }
+                rest = without;
+            } else {
+                return Ok(result + self.acceptance_combinations(&rule.target, ratings)?);
+            }
+        }
+
+        Err(anyhow!("ruleset did not end with conditionless rule"))
+    }
+
+    /// Draw every rule as an edge to its target, labelled with its condition.
+    fn dot(&self) -> Dot {
+        let mut dot = Dot::new(true);
+        dot.style("start", &[("shape", "box"), ("style", "bold")])
+            .style("workflow", &[("shape", "box")])
+            .style(
+                "accept",
+                &[("shape", "doublecircle"), ("color", "darkgreen")],
+            )
+            .style("reject", &[("shape", "circle"), ("color", "gray")])
+            .node("A", "A", Some("accept"))
+            .node("R", "R", Some("reject"));
+
+        let mut names: Vec<&String> = self.inner.keys().collect();
+        names.sort();
+        for name in names {
+            let kind = if name == "in" { "start" } else { "workflow" };
+            dot.node(name, name, Some(kind));
+            for rule in &self.inner[name].rules {
+                let condition = rule.condition.as_ref().map(Condition::to_string);
+                dot.edge(name, &rule.target, condition.as_deref());
+            }
+        }
+        dot
+    }
+}
+
+#[derive(Debug)]
+struct Ratings(HashMap<Category, i64>);
+
+impl Ratings {
+    fn from_line(re: &Regex, s: &str) -> Result<Self> {
+        let captures = re.captures(s).ok_or(anyhow!("invalid ratings: {s}"))?;
+
+        let mut result = Self(HashMap::new());
+        result.0.insert(Category::X, captures[1].parse()?);
+        result.0.insert(Category::M, captures[2].parse()?);
+        result.0.insert(Category::A, captures[3].parse()?);
+        result.0.insert(Category::S, captures[4].parse()?);
+        Ok(result)
+    }
+
+    fn sum(&self) -> i64 {
+        self.0.values().sum()
+    }
+}
+
+pub struct System {
+    workflows: Workflows,
+    parts: Vec<Ratings>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let workflow_re = Regex::new(r"(.+)\{(.+)\}")?;
+        let ratings_re = Regex::new(r"\{x=([0-9]+),m=([0-9]+),a=([0-9]+),s=([0-9]+)\}")?;
+        let mut lines = input.lines();
+
+        let mut workflows = HashMap::new();
+        for line in lines.by_ref().take_while(|s| !s.is_empty()) {
+            let workflow = Workflow::from_line(&workflow_re, line)?;
+            workflows.insert(workflow.name.clone(), workflow);
+        }
+
+        let parts = lines
+            .map(|line| Ratings::from_line(&ratings_re, line))
+            .collect::<Result<_>>()?;
+
+        Ok(System {
+            workflows: Workflows { inner: workflows },
+            parts,
+        })
+    }
+
+    fn part1(system: &Self::Input) -> Result<Answer> {
+        let mut result = 0;
+        for ratings in &system.parts {
+            if system.workflows.accepts(ratings)? {
+                result += ratings.sum();
+            }
+        }
+
+        Ok(result.into())
+    }
+
+    fn part2(system: &Self::Input) -> Result<Answer> {
+        let result = system.workflows.acceptance_combinations("in", RATINGS)?;
+        Ok(result.into())
+    }
+
+    fn dot(system: &Self::Input) -> Result<Dot> {
+        Ok(system.workflows.dot())
+    }
+}
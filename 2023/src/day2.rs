@@ -1,8 +1,6 @@
-#![warn(clippy::pedantic)]
-
 use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
 use regex::Regex;
-use std::fs;
 use std::str::FromStr;
 
 #[derive(Debug)]
@@ -43,7 +41,7 @@ impl FromStr for Grab {
 }
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     id: u32,
     grabs: Vec<Grab>,
 }
@@ -55,15 +53,15 @@ impl FromStr for Game {
         let re = Regex::new(r"Game ([0-9][0-9]*): (.+)$")?;
         let caps = re
             .captures(line)
-            .ok_or(anyhow!("unexpected game format: {}", line))?;
+            .ok_or(anyhow!("unexpected game format: {line}"))?;
         let id: u32 = caps
             .get(1)
-            .ok_or(anyhow!("cannot find game id: {}", line))?
+            .ok_or(anyhow!("cannot find game id: {line}"))?
             .as_str()
             .parse()?;
         let grabs_str = caps
             .get(2)
-            .ok_or(anyhow!("cannot find grabs: {}", line))?
+            .ok_or(anyhow!("cannot find grabs: {line}"))?
             .as_str();
         let grabs = grabs_str
             .split("; ")
@@ -94,22 +92,56 @@ impl Game {
         }
         true
     }
+
+    pub fn power(&self) -> u32 {
+        let mut max_red = 0;
+        let mut max_green = 0;
+        let mut max_blue = 0;
+
+        for grab in &self.grabs {
+            if let Some(r) = grab.red {
+                if r > max_red {
+                    max_red = r;
+                }
+            }
+            if let Some(g) = grab.green {
+                if g > max_green {
+                    max_green = g;
+                }
+            }
+            if let Some(b) = grab.blue {
+                if b > max_blue {
+                    max_blue = b;
+                }
+            }
+        }
+
+        max_red * max_green * max_blue
+    }
 }
 
-fn main() -> Result<()> {
-    let input = fs::read_to_string("input.txt")?;
-    let games = input
-        .lines()
-        .map(Game::from_str)
-        .collect::<Result<Vec<Game>>>()?;
-
-    let mut result = 0;
-    for game in games {
-        if game.is_possible(12, 13, 14) {
-            result += game.id;
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(Game::from_str).collect()
+    }
+
+    fn part1(games: &Self::Input) -> Result<Answer> {
+        let mut result = 0;
+        for game in games {
+            if game.is_possible(12, 13, 14) {
+                result += game.id;
+            }
         }
+
+        Ok(result.into())
     }
 
-    println!("Result: {result}");
-    Ok(())
+    fn part2(games: &Self::Input) -> Result<Answer> {
+        let result: u32 = games.iter().map(Game::power).sum();
+        Ok(result.into())
+    }
 }
@@ -0,0 +1,246 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    dot::Dot,
+    periodicity::{EventLog, Periodic},
+    solution::{Answer, Solution},
+};
+use regex::Regex;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(Clone, Debug, PartialEq)]
+enum ModuleKind {
+    Broadcast,
+    FlipFlop(bool),
+    Conjunction(HashMap<String, bool>), // boolean indicates is_high for input
+}
+
+#[derive(Debug)]
+struct Pulse {
+    src: String,
+    is_low: bool,
+    dest: String,
+}
+
+#[derive(Clone, Debug)]
+struct Module {
+    name: String,
+    kind: ModuleKind,
+    destinations: Vec<String>,
+}
+
+impl Module {
+    fn from_line(re: &Regex, s: &str) -> Result<Self> {
+        let captures = re.captures(s).ok_or(anyhow!("invalid module: {s}"))?;
+        let kind = match &captures[1] {
+            "%" => ModuleKind::FlipFlop(false),
+            "&" => ModuleKind::Conjunction(HashMap::new()),
+            _ => ModuleKind::Broadcast,
+        };
+        let name = captures[2].to_owned();
+        let destinations = captures[3].split(", ").map(str::to_owned).collect();
+        Ok(Self {
+            name,
+            kind,
+            destinations,
+        })
+    }
+
+    fn handle_pulse<F>(&mut self, pulse: &Pulse, mut output_new_pulse: F)
+    where
+        F: FnMut(Pulse),
+    {
+        let is_low = match &mut self.kind {
+            ModuleKind::Broadcast => pulse.is_low,
+            ModuleKind::FlipFlop(state) => {
+                if !pulse.is_low {
+                    return;
+                }
+                *state = !*state;
+                !*state
+            }
+            ModuleKind::Conjunction(inputs) => {
+                inputs.insert(pulse.src.clone(), !pulse.is_low);
+                inputs.values().all(|is_high| *is_high)
+            }
+        };
+
+        for dest in &self.destinations {
+            output_new_pulse(Pulse {
+                src: self.name.clone(),
+                is_low,
+                dest: dest.clone(),
+            });
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Network {
+    modules: HashMap<String, Module>,
+    inputs: HashMap<String, HashSet<String>>,
+}
+
+impl Network {
+    /// Push the button once, and pass every pulse it causes to `on_pulse`,
+    /// starting with the button's own pulse.
+    fn press_button(&mut self, mut on_pulse: impl FnMut(&Pulse)) {
+        let mut queue = VecDeque::from([Pulse {
+            src: "button".into(),
+            is_low: true,
+            dest: "broadcaster".into(),
+        }]);
+        while let Some(pulse) = queue.pop_front() {
+            on_pulse(&pulse);
+            if let Some(module) = self.modules.get_mut(&pulse.dest) {
+                module.handle_pulse(&pulse, |p| queue.push_back(p));
+            }
+        }
+    }
+
+    /// Draw the modules with their kinds, including those that only receive
+    /// pulses, such as `rx`.
+    fn dot(&self) -> Dot {
+        let mut dot = Dot::new(true);
+        dot.style("broadcast", &[("shape", "doubleoctagon")])
+            .style("flip-flop", &[("shape", "box")])
+            .style(
+                "conjunction",
+                &[("shape", "invtriangle"), ("color", "blue")],
+            )
+            .style("output", &[("shape", "plaintext")]);
+
+        let mut names: Vec<&String> = self.modules.keys().collect();
+        names.sort();
+        for name in &names {
+            let (kind, label) = match self.modules[*name].kind {
+                ModuleKind::Broadcast => ("broadcast", (*name).clone()),
+                ModuleKind::FlipFlop(_) => ("flip-flop", format!("%{name}")),
+                ModuleKind::Conjunction(_) => ("conjunction", format!("&{name}")),
+            };
+            dot.node(name, &label, Some(kind));
+        }
+        let mut outputs: Vec<&String> = self
+            .inputs
+            .keys()
+            .filter(|name| !self.modules.contains_key(*name))
+            .collect();
+        outputs.sort();
+        for name in outputs {
+            dot.node(name, name, Some("output"));
+        }
+
+        for name in names {
+            for destination in &self.modules[name].destinations {
+                dot.edge(name, destination, None);
+            }
+        }
+        dot
+    }
+}
+
+/// Give up on part 2 if the signals are not all periodic after this many
+/// button presses.
+const MAX_PRESSES: u64 = 1_000_000;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = Regex::new(r"^([%&]?)(.+) -> (.+)$")?;
+        let mut modules: HashMap<String, Module> = input
+            .lines()
+            .map(|line| Module::from_line(&re, line))
+            .map(|m| m.map(|m| (m.name.clone(), m)))
+            .collect::<Result<_>>()?;
+
+        // Get the inputs for each of the modules.
+        let mut inputs: HashMap<String, HashSet<String>> = HashMap::new();
+        for module in modules.values() {
+            for dest in &module.destinations {
+                inputs
+                    .entry(dest.clone())
+                    .or_default()
+                    .insert(module.name.clone());
+            }
+        }
+
+        // Set low-pulse memory for all inputs of all conjunction modules.
+        for module in modules.values_mut() {
+            if let ModuleKind::Conjunction(states) = &mut module.kind {
+                for input in inputs.get(&module.name).into_iter().flatten() {
+                    states.insert(input.clone(), false);
+                }
+            }
+        }
+
+        Ok(Network { modules, inputs })
+    }
+
+    fn part1(network: &Self::Input) -> Result<Answer> {
+        let mut network = network.clone();
+        let mut low_count: u64 = 0;
+        let mut high_count: u64 = 0;
+        for _ in 0..1000 {
+            network.press_button(|pulse| {
+                if pulse.is_low {
+                    low_count += 1;
+                } else {
+                    high_count += 1;
+                }
+            });
+        }
+
+        Ok((low_count * high_count).into())
+    }
+
+    fn part2(network: &Self::Input) -> Result<Answer> {
+        let mut network = network.clone();
+        let target_name = "rx";
+        let sources_names = network
+            .inputs
+            .get(target_name)
+            .ok_or(anyhow!("no module sends to {target_name}"))?;
+        if sources_names.len() != 1 {
+            return Err(anyhow!("{target_name} has more than one input"));
+        }
+
+        // The only incoming module to the target, and it should be a conjunction.
+        let subtarget_name = sources_names.iter().next().unwrap().clone();
+        let subtarget = &network.modules[&subtarget_name];
+
+        // The subtarget sends a low pulse to the target once all of its inputs
+        // last sent it a high pulse. Record when each input does so, until all
+        // of them turn out to be periodic, and find when they first coincide.
+        let ModuleKind::Conjunction(inputs) = &subtarget.kind else {
+            return Err(anyhow!("only target source is not a conjunction"));
+        };
+        let watched: Vec<String> = inputs.keys().cloned().collect();
+        let mut log = EventLog::new();
+        let mut button_presses = 0;
+        let signals = loop {
+            let signals: Option<Vec<Periodic>> = watched.iter().map(|w| log.periodic(w)).collect();
+            if let Some(signals) = signals {
+                break signals;
+            }
+            if button_presses == MAX_PRESSES {
+                return Err(anyhow!(
+                    "the inputs of {subtarget_name} are not periodic after {MAX_PRESSES} presses"
+                ));
+            }
+            button_presses += 1;
+            network.press_button(|pulse| {
+                if pulse.dest == subtarget_name && !pulse.is_low {
+                    log.record(pulse.src.clone(), button_presses);
+                }
+            });
+        };
+
+        Ok(Periodic::align_all(&signals)?.offset.into())
+    }
+
+    fn dot(network: &Self::Input) -> Result<Dot> {
+        Ok(network.dot())
+    }
+}
@@ -0,0 +1,88 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    dot::Dot,
+    geometry::{Coord, Direction},
+    graph::{longest_simple_path, longest_simple_path_parallel, Junctions, SimplePath},
+    grid::Grid,
+    solution::{Answer, Solution},
+};
+
+pub struct Map {
+    grid: Grid<char>,
+    start: Coord,
+    end: Coord,
+}
+
+impl Map {
+    /// The junctions ignoring slopes, and the longest hike between the
+    /// terminals through them.
+    fn longest_hike(&self) -> Result<(Junctions, SimplePath)> {
+        let junctions = Junctions::new(&self.grid, &[self.start, self.end], |&c| c != '#');
+        let path = longest_simple_path_parallel(&junctions.graph, 0, 1)
+            .ok_or(anyhow!("no path from start to end"))?;
+        Ok((junctions, path))
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse_chars(input)?;
+        if grid.height() == 0 {
+            return Err(anyhow!("empty map"));
+        }
+        let start = Coord {
+            x: grid
+                .row(0)
+                .iter()
+                .position(|&c| c == '.')
+                .ok_or(anyhow!("no start in the first row"))?,
+            y: 0,
+        };
+        let end = Coord {
+            x: grid
+                .row(grid.height() - 1)
+                .iter()
+                .position(|&c| c == '.')
+                .ok_or(anyhow!("no end in the last row"))?,
+            y: grid.height() - 1,
+        };
+
+        Ok(Map { grid, start, end })
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer> {
+        // Slopes can only be walked downhill.
+        let junctions = Junctions::directed(
+            &map.grid,
+            &[map.start, map.end],
+            |&c| c != '#',
+            |&c, d| Direction::from_char(c).is_none_or(|slope| slope == d),
+        );
+        let path = longest_simple_path(&junctions.graph, 0, 1)
+            .ok_or(anyhow!("no path from start to end"))?;
+        Ok(path.length.into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer> {
+        let (_, path) = map.longest_hike()?;
+        Ok(path.length.into())
+    }
+
+    /// The junctions ignoring slopes, with the longest hike of part 2 marked.
+    fn dot(map: &Self::Input) -> Result<Dot> {
+        let (junctions, path) = map.longest_hike()?;
+        let coords = &junctions.coords;
+        let mut dot = Dot::from_graph(&junctions.graph, |v| {
+            let label = format!("{},{}", coords[v].x, coords[v].y);
+            (label, (v < 2).then_some("terminal"))
+        });
+        dot.style("terminal", &[("shape", "doublecircle")]);
+        let ids: Vec<String> = path.vertices.iter().map(usize::to_string).collect();
+        dot.highlight(&ids);
+        Ok(dot)
+    }
+}
@@ -0,0 +1,136 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    linear::{solve, Solutions},
+    solution::{Answer, Solution},
+};
+use num::{rational::Ratio, BigInt, BigRational, ToPrimitive};
+
+/// The test area in both the x and y directions.
+const TEST_AREA: (i128, i128) = (200_000_000_000_000, 400_000_000_000_000);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Hailstone {
+    position: [i64; 3],
+    velocity: [i64; 3],
+}
+
+impl Hailstone {
+    fn from_line(line: &str) -> Result<Self> {
+        let triple = |s: &str| -> Result<[i64; 3]> {
+            let values = s
+                .split(',')
+                .map(|v| Ok(v.trim().parse()?))
+                .collect::<Result<Vec<i64>>>()?;
+            values
+                .try_into()
+                .map_err(|_| anyhow!("expected three numbers: {s}"))
+        };
+        let (position, velocity) = line
+            .split_once(" @ ")
+            .ok_or(anyhow!("invalid hailstone: {line}"))?;
+        Ok(Self {
+            position: triple(position)?,
+            velocity: triple(velocity)?,
+        })
+    }
+
+    /// Where the paths of two hailstones cross in the x-y plane, if they do so
+    /// in the future of both.
+    fn crossing(&self, other: &Self) -> Option<[Ratio<i128>; 2]> {
+        let ratio = |n: i64| Ratio::from_integer(i128::from(n));
+        // Solve for the times t and s with p1 + t * v1 == p2 + s * v2.
+        let matrix: Vec<Vec<Ratio<i128>>> = (0..2)
+            .map(|i| vec![ratio(self.velocity[i]), -ratio(other.velocity[i])])
+            .collect();
+        let rhs: Vec<Ratio<i128>> = (0..2)
+            .map(|i| ratio(other.position[i] - self.position[i]))
+            .collect();
+        let Solutions::Unique(times) = solve(&matrix, &rhs) else {
+            return None;
+        };
+        if times.iter().any(|t| *t < Ratio::from_integer(0)) {
+            return None;
+        }
+        Some([0, 1].map(|i| ratio(self.position[i]) + times[0] * ratio(self.velocity[i])))
+    }
+}
+
+/// `a × b`.
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Hailstone>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(Hailstone::from_line).collect()
+    }
+
+    fn part1(hailstones: &Self::Input) -> Result<Answer> {
+        let (min, max) = (
+            Ratio::from_integer(TEST_AREA.0),
+            Ratio::from_integer(TEST_AREA.1),
+        );
+        let mut result = 0;
+        for (i, first) in hailstones.iter().enumerate() {
+            for second in &hailstones[i + 1..] {
+                if first
+                    .crossing(second)
+                    .is_some_and(|point| point.iter().all(|c| min <= *c && *c <= max))
+                {
+                    result += 1;
+                }
+            }
+        }
+        Ok(result.into())
+    }
+
+    fn part2(hailstones: &Self::Input) -> Result<Answer> {
+        // A rock at P moving at V hits a hailstone at p moving at v when
+        // (P - p) × (V - v) == 0. The P × V term is the same for every
+        // hailstone, so subtracting the equations of two hailstones leaves
+        // P × (v' - v) + (p' - p) × V == p' × v' - p × v, which is linear.
+        let widen = |a: [i64; 3]| a.map(i128::from);
+        let big = |n: i128| BigRational::from_integer(BigInt::from(n));
+        let (first, rest) = hailstones.split_first().ok_or(anyhow!("no hailstones"))?;
+        let (position, velocity) = (widen(first.position), widen(first.velocity));
+
+        let mut matrix = Vec::new();
+        let mut rhs = Vec::new();
+        for other in rest.iter().take(3) {
+            let (other_position, other_velocity) = (widen(other.position), widen(other.velocity));
+            let dv = [0, 1, 2].map(|i| other_velocity[i] - velocity[i]);
+            let dp = [0, 1, 2].map(|i| other_position[i] - position[i]);
+            for row in [
+                [0, dv[2], -dv[1], 0, -dp[2], dp[1]],
+                [-dv[2], 0, dv[0], dp[2], 0, -dp[0]],
+                [dv[1], -dv[0], 0, -dp[1], dp[0], 0],
+            ] {
+                matrix.push(row.map(big).to_vec());
+            }
+            let (after, before) = (
+                cross(other_position, other_velocity),
+                cross(position, velocity),
+            );
+            rhs.extend((0..3).map(|i| big(after[i] - before[i])));
+        }
+
+        let solutions = solve(&matrix, &rhs);
+        let rock = matches!(solutions, Solutions::Unique(_))
+            .then(|| solutions.integer())
+            .flatten()
+            .ok_or(anyhow!("no single rock with integer position and velocity"))?;
+        let result: BigInt = rock[..3].iter().sum();
+        Ok(result
+            .to_i128()
+            .ok_or(anyhow!("the answer overflows"))?
+            .into())
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    solution::{Answer, Solution},
+    union_find::UnionFind,
+};
+use std::collections::HashMap;
+
+/// The number of wires to cut, which the puzzle promises is the minimum cut.
+const CUT_SIZE: usize = 3;
+
+/// Give up after this many contractions that find a larger cut.
+const MAX_TRIES: usize = 100_000;
+
+#[derive(Debug)]
+pub struct Wiring {
+    components: usize,
+    wires: Vec<(usize, usize)>,
+}
+
+/// A xorshift generator, which is random enough to shuffle the wires.
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        usize::try_from(self.0 % n as u64).unwrap()
+    }
+}
+
+impl Wiring {
+    /// Contract randomly chosen wires, by Karger's algorithm, until only two
+    /// groups of components are left. Returns the groups if the wires between
+    /// them are a minimum cut.
+    fn contract(&self, rng: &mut Rng) -> Option<UnionFind> {
+        let mut wires = self.wires.clone();
+        for i in (1..wires.len()).rev() {
+            wires.swap(i, rng.below(i + 1));
+        }
+
+        let mut groups = UnionFind::new(self.components);
+        for &(a, b) in &wires {
+            if groups.count() == 2 {
+                break;
+            }
+            groups.union(a, b);
+        }
+        let cut = wires.iter().filter(|&&(a, b)| !groups.same(a, b)).count();
+        (cut == CUT_SIZE).then_some(groups)
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Wiring;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut indices = HashMap::new();
+        let mut index = |name: &str| {
+            let next = indices.len();
+            *indices.entry(name.to_owned()).or_insert(next)
+        };
+
+        let mut wires = Vec::new();
+        for line in input.lines() {
+            let (source, targets) = line
+                .split_once(": ")
+                .ok_or(anyhow!("invalid line: {line}"))?;
+            let source = index(source);
+            for target in targets.split_whitespace() {
+                wires.push((source, index(target)));
+            }
+        }
+
+        Ok(Wiring {
+            components: indices.len(),
+            wires,
+        })
+    }
+
+    fn part1(wiring: &Self::Input) -> Result<Answer> {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..MAX_TRIES {
+            if let Some(mut groups) = wiring.contract(&mut rng) {
+                let result: usize = groups.sets().iter().map(Vec::len).product();
+                return Ok(result.into());
+            }
+        }
+        Err(anyhow!("no cut of {CUT_SIZE} wires found"))
+    }
+}
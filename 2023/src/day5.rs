@@ -0,0 +1,175 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    interval::{IntervalSet, OffsetMap},
+    solution::{Answer, Solution},
+};
+use itertools::Itertools;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    category_maps: HashMap<String, AlmanacMap>,
+}
+
+impl FromStr for Almanac {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let re = Regex::new(r"seeds: ([\d\s]+)\n\n((?:.|\n)+)")?;
+        let caps = re
+            .captures(s)
+            .ok_or(anyhow!("invalid almanac header: {s}"))?;
+
+        let seeds = caps
+            .get(1)
+            .unwrap()
+            .as_str()
+            .split_whitespace()
+            .map(|s| -> Result<i64> {
+                let n = s.parse()?;
+                Ok(n)
+            })
+            .collect::<Result<Vec<i64>>>()?;
+
+        let category_maps = caps
+            .get(2)
+            .unwrap()
+            .as_str()
+            .split("\n\n")
+            .map(AlmanacMap::from_str)
+            .map(|m| m.map(|m| (m.source_category.clone(), m)))
+            .collect::<Result<HashMap<String, AlmanacMap>>>()?;
+
+        Ok(Self {
+            seeds,
+            category_maps,
+        })
+    }
+}
+
+impl Almanac {
+    /// Pairs of seed numbers read as the start and length of a range.
+    fn seed_ranges(&self) -> IntervalSet<i64> {
+        self.seeds
+            .iter()
+            .tuples()
+            .map(|(&start, &len)| start..start + len)
+            .collect()
+    }
+
+    /// Compose the maps from one category through to another into one. Fails
+    /// if the maps stop, or come back round to a category, before reaching it.
+    fn chain(&self, from: &str, to: &str) -> Result<OffsetMap<i64>> {
+        let mut result = OffsetMap::identity();
+        let mut category = from;
+        let mut visited = HashSet::new();
+        while category != to {
+            if !visited.insert(category) {
+                return Err(anyhow!(
+                    "the maps from {from} go round in a circle at {category}"
+                ));
+            }
+            let map = self
+                .category_maps
+                .get(category)
+                .ok_or(anyhow!("no map from {category} on the way to {to}"))?;
+            result = result.compose(&map.offsets);
+            category = &map.destination_category;
+        }
+        Ok(result)
+    }
+}
+
+#[derive(Debug)]
+struct AlmanacMap {
+    source_category: String,
+    destination_category: String,
+    offsets: OffsetMap<i64>,
+}
+
+impl FromStr for AlmanacMap {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.lines();
+
+        let header = lines.next().ok_or(anyhow!("map is empty: {s}"))?;
+        let header_re = Regex::new(r"(.+)-to-(.+) map:")?;
+        let header_caps = header_re
+            .captures(header)
+            .ok_or(anyhow!("invalid map header: {header}"))?;
+
+        let source_category = header_caps.get(1).unwrap().as_str().to_owned();
+        let destination_category = header_caps.get(2).unwrap().as_str().to_owned();
+        let ranges = lines
+            .map(|line| {
+                let range = AlmanacRange::from_str(line)?;
+                Ok((range.source_range, range.destination_offset))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            source_category,
+            destination_category,
+            offsets: OffsetMap::new(ranges)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct AlmanacRange {
+    source_range: Range<i64>,
+    destination_offset: i64,
+}
+
+impl FromStr for AlmanacRange {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let re = Regex::new(r"(\d+) (\d+) (\d+)")?;
+        let caps = re.captures(s).ok_or(anyhow!("invalid range: {s}"))?;
+
+        let destination_start = caps.get(1).unwrap().as_str().parse::<i64>()?;
+        let source_start = caps.get(2).unwrap().as_str().parse::<i64>()?;
+        let range_len = caps.get(3).unwrap().as_str().parse::<i64>()?;
+
+        let source_range = source_start..source_start + range_len;
+        let destination_offset = destination_start - source_start;
+
+        Ok(Self {
+            source_range,
+            destination_offset,
+        })
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Almanac::from_str(input)
+    }
+
+    fn part1(almanac: &Self::Input) -> Result<Answer> {
+        let locations = almanac.chain("seed", "location")?;
+        let result = almanac
+            .seeds
+            .iter()
+            .map(|&seed| locations.apply(seed))
+            .min();
+        Ok(result.ok_or(anyhow!("no seeds"))?.into())
+    }
+
+    fn part2(almanac: &Self::Input) -> Result<Answer> {
+        let locations = almanac.chain("seed", "location")?;
+        let reached = locations.image(&almanac.seed_ranges());
+        let lowest = reached.ranges().first().ok_or(anyhow!("no seed ranges"))?;
+        Ok(lowest.start.into())
+    }
+}
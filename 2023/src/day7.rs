@@ -1,13 +1,13 @@
-#![warn(clippy::pedantic)]
-
 use anyhow::{anyhow, Result};
-use std::{char, collections::HashMap, fs, str::FromStr};
+use aoc_common::solution::{Answer, Solution};
+use std::{char, collections::HashMap, str::FromStr};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 enum Card {
     Ace,
     King,
     Queen,
+    Jack,
     Ten,
     Nine,
     Eight,
@@ -35,16 +35,17 @@ impl Card {
             '4' => Ok(Self::Four),
             '3' => Ok(Self::Three),
             '2' => Ok(Self::Two),
-            'J' => Ok(Self::Joker),
-            _ => Err(anyhow!("char is not a valid card: {}", c)),
+            'J' => Ok(Self::Jack),
+            _ => Err(anyhow!("char is not a valid card: {c}")),
         }
     }
 
     fn rank(&self) -> u32 {
         match self {
-            Self::Ace => 12,
-            Self::King => 11,
-            Self::Queen => 10,
+            Self::Ace => 13,
+            Self::King => 12,
+            Self::Queen => 11,
+            Self::Jack => 10,
             Self::Ten => 9,
             Self::Nine => 8,
             Self::Eight => 7,
@@ -59,7 +60,7 @@ impl Card {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum HandType {
     FiveOfAKind,
     FourOfAKind,
@@ -131,20 +132,36 @@ impl HandType {
     }
 }
 
-#[derive(Debug)]
-struct Hand {
+#[derive(Debug, Clone)]
+pub struct Hand {
     bid: u32,
     cards: [Card; 5],
     htype: HandType,
 }
 
+impl Hand {
+    /// Read every jack as a joker instead.
+    fn with_jokers(&self) -> Self {
+        let cards = self.cards.clone().map(|card| match card {
+            Card::Jack => Card::Joker,
+            card => card,
+        });
+        let htype = HandType::from_cards(&cards);
+        Self {
+            bid: self.bid,
+            cards,
+            htype,
+        }
+    }
+}
+
 impl FromStr for Hand {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
         let (raw_cards, raw_bid) = s
             .split_once(' ')
-            .ok_or(anyhow!("no space char in hand: {}", s))?;
+            .ok_or(anyhow!("no space char in hand: {s}"))?;
 
         let bid = raw_bid.parse()?;
         let cards = raw_cards
@@ -152,7 +169,7 @@ impl FromStr for Hand {
             .map(Card::from_char)
             .collect::<Result<Vec<Card>>>()?
             .try_into()
-            .map_err(|e| anyhow!("error while converting card vector to array: {:?}", e))?;
+            .map_err(|e| anyhow!("error while converting card vector to array: {e:?}"))?;
         let hand_type = HandType::from_cards(&cards);
 
         Ok(Self {
@@ -163,14 +180,7 @@ impl FromStr for Hand {
     }
 }
 
-fn main() -> Result<()> {
-    let file = fs::read_to_string("input.txt")?;
-
-    let mut hands = file
-        .lines()
-        .map(Hand::from_str)
-        .collect::<Result<Vec<Hand>>>()?;
-
+fn total_winnings(mut hands: Vec<Hand>) -> usize {
     hands.sort_by(|a, b| {
         let a_rank = a.htype.rank();
         let b_rank = b.htype.rank();
@@ -188,12 +198,28 @@ fn main() -> Result<()> {
         }
     });
 
-    let result = hands
+    hands
         .iter()
         .enumerate()
         .map(|(i, hand)| (i + 1) * hand.bid as usize)
-        .sum::<usize>();
-    println!("{result}");
+        .sum::<usize>()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(Hand::from_str).collect()
+    }
+
+    fn part1(hands: &Self::Input) -> Result<Answer> {
+        Ok(total_winnings(hands.clone()).into())
+    }
 
-    Ok(())
+    fn part2(hands: &Self::Input) -> Result<Answer> {
+        let hands = hands.iter().map(Hand::with_jokers).collect();
+        Ok(total_winnings(hands).into())
+    }
 }
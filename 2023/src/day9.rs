@@ -0,0 +1,60 @@
+use anyhow::Result;
+use aoc_common::solution::{Answer, Solution};
+
+fn extrapolate_history(values: &[i64]) -> Result<i64> {
+    if values.iter().all(|v| *v == 0) {
+        return Ok(0);
+    }
+    let mut child = Vec::new();
+    for i in 1..values.len() {
+        child.push(values[i] - values[i - 1]);
+    }
+    Ok(values.last().unwrap() + extrapolate_history(&child)?)
+}
+
+fn extrapolate_history_backwards(values: &[i64]) -> Result<i64> {
+    if values.iter().all(|v| *v == 0) {
+        return Ok(0);
+    }
+    let mut child = Vec::new();
+    for i in 1..values.len() {
+        child.push(values[i] - values[i - 1]);
+    }
+    Ok(values.first().unwrap() - extrapolate_history_backwards(&child)?)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|s| {
+                        let v = s.parse()?;
+                        Ok(v)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(histories: &Self::Input) -> Result<Answer> {
+        let result = histories
+            .iter()
+            .map(|values| extrapolate_history(values))
+            .sum::<Result<i64>>()?;
+        Ok(result.into())
+    }
+
+    fn part2(histories: &Self::Input) -> Result<Answer> {
+        let result = histories
+            .iter()
+            .map(|values| extrapolate_history_backwards(values))
+            .sum::<Result<i64>>()?;
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,46 @@
+#![warn(clippy::pedantic)]
+#![allow(clippy::missing_errors_doc)]
+
+use aoc_common::solution::Year;
+
+mod day10;
+mod day11;
+mod day12;
+mod day14;
+mod day15;
+mod day17;
+mod day18;
+mod day19;
+mod day2;
+mod day20;
+mod day23;
+mod day24;
+mod day25;
+mod day4;
+mod day5;
+mod day7;
+mod day9;
+
+pub const YEAR: Year = Year {
+    year: 2023,
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    days: &[
+        (2, &day2::Day2),
+        (4, &day4::Day4),
+        (5, &day5::Day5),
+        (7, &day7::Day7),
+        (9, &day9::Day9),
+        (10, &day10::Day10),
+        (11, &day11::Day11),
+        (12, &day12::Day12),
+        (14, &day14::Day14),
+        (15, &day15::Day15),
+        (17, &day17::Day17),
+        (18, &day18::Day18),
+        (19, &day19::Day19),
+        (20, &day20::Day20),
+        (23, &day23::Day23),
+        (24, &day24::Day24),
+        (25, &day25::Day25),
+    ],
+};
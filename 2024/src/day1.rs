@@ -0,0 +1,68 @@
+use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+pub struct Lists {
+    left: Vec<i32>,
+    right: Vec<i32>,
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Lists;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (left, right): (Vec<i32>, Vec<i32>) = input
+            .lines()
+            .map(|line| {
+                let (a, b) = line
+                    .split_once("   ")
+                    .ok_or(anyhow!("invalid line: {line}"))?;
+                Ok((a.parse()?, b.parse()?))
+            })
+            .collect::<Result<Vec<(i32, i32)>>>()?
+            .into_iter()
+            .unzip();
+
+        if left.len() != right.len() {
+            return Err(anyhow!(
+                "list size mismatch: left={} right={}",
+                left.len(),
+                right.len()
+            ));
+        }
+
+        Ok(Lists { left, right })
+    }
+
+    fn part1(lists: &Self::Input) -> Result<Answer> {
+        let mut left = lists.left.clone();
+        let mut right = lists.right.clone();
+        left.sort_unstable();
+        right.sort_unstable();
+
+        let mut result = 0;
+        for (a, b) in left.iter().zip(right.iter()) {
+            result += (a - b).abs();
+        }
+
+        Ok(result.into())
+    }
+
+    fn part2(lists: &Self::Input) -> Result<Answer> {
+        let right_occurrences = lists.right.iter().fold(HashMap::new(), |mut acc, &x| {
+            *acc.entry(x).or_insert(0) += 1;
+            acc
+        });
+
+        let mut result = 0;
+        for v in &lists.left {
+            if let Some(&count) = right_occurrences.get(v) {
+                result += v * count;
+            }
+        }
+
+        Ok(result.into())
+    }
+}
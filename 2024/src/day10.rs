@@ -0,0 +1,75 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    geometry::Coord,
+    grid::Grid,
+    solution::{Answer, Solution},
+};
+use std::collections::{HashMap, HashSet};
+
+// Run a search from a trailhead to find all nines reachable from it.
+// Makes sure to count nines that are reachable from multiple walks only once.
+fn score(map: &Grid<u32>, trailhead: Coord) -> usize {
+    let mut reachable_nines = HashSet::new();
+    let mut queue = Vec::from([(trailhead, 0)]);
+
+    while let Some((coord, height)) = queue.pop() {
+        for neighbour in map.neighbours4(coord) {
+            let neighbour_height = map[neighbour];
+            if neighbour_height == height + 1 {
+                if neighbour_height == 9 {
+                    reachable_nines.insert(neighbour);
+                } else {
+                    queue.push((neighbour, height + 1));
+                }
+            }
+        }
+    }
+
+    reachable_nines.len()
+}
+
+// Run a BFS from a trailhead. We can count the number of ways to reach a
+// coordinate by summing the number of ways to reach its incoming neighbours.
+fn rating(map: &Grid<u32>, trailhead: Coord) -> usize {
+    let mut queue = HashMap::new();
+    queue.insert(trailhead, 1);
+
+    for height in 1..=9 {
+        let prev = std::mem::take(&mut queue);
+        for (coord, rating) in prev {
+            for neighbour in map.neighbours4(coord) {
+                if map[neighbour] == height {
+                    *queue.entry(neighbour).or_insert(0) += rating;
+                }
+            }
+        }
+    }
+
+    queue.into_values().sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, |c| c.to_digit(10).ok_or(anyhow!("not a digit")))
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer> {
+        let result: usize = map
+            .positions(|&cell| cell == 0)
+            .map(|trailhead| score(map, trailhead))
+            .sum();
+        Ok(result.into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer> {
+        let result: usize = map
+            .positions(|&cell| cell == 0)
+            .map(|trailhead| rating(map, trailhead))
+            .sum();
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,103 @@
+use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+struct CachedStoneCounter {
+    memo: HashMap<u64, HashMap<u64, u64>>,
+}
+
+impl CachedStoneCounter {
+    fn new() -> Self {
+        Self {
+            memo: HashMap::new(),
+        }
+    }
+
+    fn stone_count(&mut self, n: u64, blinks: u64) -> u64 {
+        // Without blinks, no new stones can be created.
+        if blinks == 0 {
+            return 1;
+        }
+
+        // Check cache for result.
+        if let Some(m) = self.memo.get(&n) {
+            if let Some(c) = m.get(&blinks) {
+                return *c;
+            }
+        }
+
+        // Case distinction according to rules.
+        let result = if n == 0 {
+            self.stone_count(1, blinks - 1)
+        } else {
+            let digits = n.ilog10() + 1; // no. digits
+            if digits.is_multiple_of(2) {
+                let half = digits / 2;
+                let mask = 10u64.pow(half);
+                let first_half = n / mask; // first half of digits
+                let second_half = n % mask; // second half of digits
+                self.stone_count(first_half, blinks - 1) + self.stone_count(second_half, blinks - 1)
+            } else {
+                self.stone_count(n * 2024, blinks - 1)
+            }
+        };
+
+        // Store result in cache and return.
+        self.memo.entry(n).or_default().insert(blinks, result);
+        result
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .strip_suffix('\n')
+            .ok_or(anyhow!("no newline at end of file"))?
+            .split_whitespace()
+            .map(|s| Ok(s.parse::<u64>()?))
+            .collect()
+    }
+
+    fn part1(stones: &Self::Input) -> Result<Answer> {
+        let blinks = 25;
+        let mut stones = stones.clone();
+
+        for _ in 0..blinks {
+            for stone in std::mem::take(&mut stones) {
+                if stone == 0 {
+                    stones.push(1);
+                } else {
+                    let digits = stone.ilog10() + 1;
+                    if digits.is_multiple_of(2) {
+                        let half = digits / 2;
+                        let mask = 10u64.pow(half);
+                        let first_half = stone / mask;
+                        let second_half = stone % mask;
+                        stones.push(first_half);
+                        stones.push(second_half);
+                    } else {
+                        stones.push(stone * 2024);
+                    }
+                }
+            }
+        }
+
+        let result = stones.len();
+        Ok(result.into())
+    }
+
+    fn part2(start_stones: &Self::Input) -> Result<Answer> {
+        let blinks = 75;
+        let mut result = 0;
+        let mut counter = CachedStoneCounter::new();
+        for &stone in start_stones {
+            result += counter.stone_count(stone, blinks);
+        }
+
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,27 @@
+use anyhow::Result;
+use aoc_common::{
+    grid::Grid,
+    solution::{Answer, Solution},
+    union_find::{regions, Region},
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Region>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let plots = Grid::parse_chars(input)?;
+        Ok(regions(&plots, |a, b| a == b))
+    }
+
+    fn part1(regions: &Self::Input) -> Result<Answer> {
+        let result: usize = regions.iter().map(|r| r.area() * r.perimeter).sum();
+        Ok(result.into())
+    }
+
+    fn part2(regions: &Self::Input) -> Result<Answer> {
+        let result: usize = regions.iter().map(|r| r.area() * r.sides).sum();
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,187 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    linear::{solve, Solutions},
+    solution::{Answer, Solution},
+};
+use num::{rational::Ratio, Integer};
+use regex::Regex;
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+pub struct Machine {
+    button_a_x: i64,
+    button_a_y: i64,
+    button_b_x: i64,
+    button_b_y: i64,
+    prize_x: i64,
+    prize_y: i64,
+    a_cost: i64,
+    b_cost: i64,
+}
+
+impl Machine {
+    /// The prize is actually much further away than the input says.
+    const PRIZE_OFFSET: i64 = 10_000_000_000_000;
+
+    /// The costs of pressing the buttons, unless the input says otherwise.
+    const DEFAULT_A_COST: i64 = 3;
+    const DEFAULT_B_COST: i64 = 1;
+
+    fn with_corrected_prize(&self) -> Self {
+        Self {
+            prize_x: self.prize_x + Self::PRIZE_OFFSET,
+            prize_y: self.prize_y + Self::PRIZE_OFFSET,
+            ..*self
+        }
+    }
+
+    /// The fewest tokens that win the prize, if it can be won at all, or an
+    /// error if that many tokens overflow.
+    fn min_prize_cost(&self, max_presses: Option<i64>) -> Result<Option<i64>> {
+        let ratio = |n: i64| Ratio::from_integer(i128::from(n));
+        let matrix = [
+            vec![ratio(self.button_a_x), ratio(self.button_b_x)],
+            vec![ratio(self.button_a_y), ratio(self.button_b_y)],
+        ];
+        let (a, b) = match solve(&matrix, &[ratio(self.prize_x), ratio(self.prize_y)]) {
+            solutions @ Solutions::Unique(_) => {
+                let Some(presses) = solutions.integer() else {
+                    return Ok(None);
+                };
+                (presses[0], presses[1])
+            }
+            Solutions::Family { .. } => match self.min_collinear_presses(max_presses) {
+                Some(presses) => presses,
+                None => return Ok(None),
+            },
+            Solutions::Inconsistent => return Ok(None),
+        };
+
+        let (Ok(a), Ok(b)) = (i64::try_from(a), i64::try_from(b)) else {
+            return Ok(None);
+        };
+        if a < 0 || b < 0 || max_presses.is_some_and(|max| a > max || b > max) {
+            return Ok(None);
+        }
+
+        a.checked_mul(self.a_cost)
+            .zip(b.checked_mul(self.b_cost))
+            .and_then(|(a, b)| a.checked_add(b))
+            .map(Some)
+            .ok_or(anyhow!(
+                "the cost of the prize at {}, {} overflows",
+                self.prize_x,
+                self.prize_y
+            ))
+    }
+
+    /// The cheapest presses when both buttons move the claw along the line
+    /// through the prize, so that only one equation is left to solve.
+    fn min_collinear_presses(&self, max_presses: Option<i64>) -> Option<(i128, i128)> {
+        // One of the axes still says how far along the line the claw goes.
+        let (a_move, b_move, target) = if (self.button_a_x, self.button_b_x) == (0, 0) {
+            (self.button_a_y, self.button_b_y, self.prize_y)
+        } else {
+            (self.button_a_x, self.button_b_x, self.prize_x)
+        };
+        let (a_move, b_move, target) = (i128::from(a_move), i128::from(b_move), i128::from(target));
+
+        // The solutions of a * a_move + b * b_move == target are
+        // (a0 + k * a_shift, b0 - k * b_shift) for every integer k. Buttons
+        // that do not move the claw at all only win a prize it is already at.
+        let gcd = a_move.extended_gcd(&b_move);
+        if gcd.gcd == 0 {
+            return (target == 0).then_some((0, 0));
+        }
+        if target % gcd.gcd != 0 {
+            return None;
+        }
+        let (a0, b0) = (gcd.x * (target / gcd.gcd), gcd.y * (target / gcd.gcd));
+        let (a_shift, b_shift) = (b_move / gcd.gcd, a_move / gcd.gcd);
+
+        // Narrow k down to where both press counts are in range.
+        let floor_div = |n: i128, d: i128| n.div_euclid(d);
+        let ceil_div = |n: i128, d: i128| -(-n).div_euclid(d);
+        let mut lower = Vec::new();
+        let mut upper = Vec::new();
+        if a_shift > 0 {
+            lower.push(ceil_div(-a0, a_shift));
+            upper.extend(max_presses.map(|max| floor_div(i128::from(max) - a0, a_shift)));
+        }
+        if b_shift > 0 {
+            upper.push(floor_div(b0, b_shift));
+            lower.extend(max_presses.map(|max| ceil_div(b0 - i128::from(max), b_shift)));
+        }
+        let (low, high) = (lower.into_iter().max(), upper.into_iter().min());
+
+        // The cost changes by the same amount with every step of k, so the
+        // cheapest presses are at one end of the range.
+        let slope = i128::from(self.a_cost) * a_shift - i128::from(self.b_cost) * b_shift;
+        let k = match slope.cmp(&0) {
+            Ordering::Greater => low?,
+            Ordering::Less => high?,
+            Ordering::Equal => low.or(high).unwrap_or(0),
+        };
+        if low.is_some_and(|low| k < low) || high.is_some_and(|high| k > high) {
+            return None;
+        }
+        Some((a0 + k * a_shift, b0 - k * b_shift))
+    }
+}
+
+/// The tokens spent on the prizes that can be won.
+fn total_cost(costs: impl Iterator<Item = Result<Option<i64>>>) -> Result<Answer> {
+    let mut result: i64 = 0;
+    for cost in costs {
+        if let Some(cost) = cost? {
+            result = result
+                .checked_add(cost)
+                .ok_or(anyhow!("the total cost overflows"))?;
+        }
+    }
+    Ok(result.into())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // A button line may end with its cost, as in ", Cost=3".
+        let re = Regex::new(
+            r"Button A: X\+([0-9]+), Y\+([0-9]+)(?:, Cost=([0-9]+))?\nButton B: X\+([0-9]+), Y\+([0-9]+)(?:, Cost=([0-9]+))?\nPrize: X=([0-9]+), Y=([0-9]+)",
+        )?;
+
+        re.captures_iter(input)
+            .map(|cap| {
+                Ok(Machine {
+                    button_a_x: cap[1].parse()?,
+                    button_a_y: cap[2].parse()?,
+                    a_cost: cap
+                        .get(3)
+                        .map_or(Ok(Machine::DEFAULT_A_COST), |m| m.as_str().parse())?,
+                    button_b_x: cap[4].parse()?,
+                    button_b_y: cap[5].parse()?,
+                    b_cost: cap
+                        .get(6)
+                        .map_or(Ok(Machine::DEFAULT_B_COST), |m| m.as_str().parse())?,
+                    prize_x: cap[7].parse()?,
+                    prize_y: cap[8].parse()?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(machines: &Self::Input) -> Result<Answer> {
+        total_cost(machines.iter().map(|m| m.min_prize_cost(Some(100))))
+    }
+
+    fn part2(machines: &Self::Input) -> Result<Answer> {
+        total_cost(
+            machines
+                .iter()
+                .map(|m| m.with_corrected_prize().min_prize_cost(None)),
+        )
+    }
+}
@@ -1,10 +1,9 @@
-#![warn(clippy::pedantic)]
-
 use anyhow::Result;
+use aoc_common::solution::{Answer, Solution};
 use regex::Regex;
 
-#[derive(Debug)]
-struct Robot {
+#[derive(Debug, Clone)]
+pub struct Robot {
     x: isize,
     y: isize,
     vx: isize,
@@ -63,35 +62,37 @@ impl Area {
     }
 }
 
-fn main() -> Result<()> {
-    let file = std::fs::read_to_string("inputs/14-input.txt")?;
+pub struct Day14;
 
-    let re = Regex::new(r"p=([0-9]+),([0-9]+) v=(-?[0-9]+),(-?[0-9]+)")?;
-
-    let robots = re
-        .captures_iter(&file)
-        .map(|cap| {
-            Ok(Robot {
-                x: cap[1].parse()?,
-                y: cap[2].parse()?,
-                vx: cap[3].parse()?,
-                vy: cap[4].parse()?,
-            })
-        })
-        .collect::<Result<Vec<_>>>()?;
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
 
-    let mut area = Area {
-        width: 101,
-        height: 103,
-        robots,
-    };
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = Regex::new(r"p=([0-9]+),([0-9]+) v=(-?[0-9]+),(-?[0-9]+)")?;
 
-    for _ in 0..100 {
-        area.tick();
+        re.captures_iter(input)
+            .map(|cap| {
+                Ok(Robot {
+                    x: cap[1].parse()?,
+                    y: cap[2].parse()?,
+                    vx: cap[3].parse()?,
+                    vy: cap[4].parse()?,
+                })
+            })
+            .collect()
     }
 
-    let result = area.safety_factor();
-    println!("result: {result}");
+    fn part1(robots: &Self::Input) -> Result<Answer> {
+        let mut area = Area {
+            width: 101,
+            height: 103,
+            robots: robots.clone(),
+        };
+
+        for _ in 0..100 {
+            area.tick();
+        }
 
-    Ok(())
+        Ok(area.safety_factor().into())
+    }
 }
@@ -0,0 +1,134 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    geometry::{Coord, Direction},
+    grid::Grid,
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Box,
+    Empty,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Result<Self> {
+        match c {
+            '#' => Ok(Self::Wall),
+            'O' => Ok(Self::Box),
+            '.' => Ok(Self::Empty),
+            _ => Err(anyhow!("invalid tile char: {c}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    tiles: Grid<Tile>,
+    robot: Coord,
+}
+
+impl Warehouse {
+    fn from_str(s: &str) -> Result<Self> {
+        let grid = Grid::parse_chars(s)?;
+        let robot = grid.find_marker('@')?;
+        let tiles = grid.try_map(|&c| {
+            if c == '@' {
+                Ok(Tile::Empty)
+            } else {
+                Tile::from_char(c)
+            }
+        })?;
+        Ok(Self { tiles, robot })
+    }
+
+    fn get_tile(&self, c: Coord) -> Option<Tile> {
+        self.tiles.get(c).copied()
+    }
+
+    fn move_box(&mut self, c: Coord, d: Direction) -> Result<()> {
+        debug_assert!(matches!(self.get_tile(c), Some(Tile::Box)));
+        let next_c = c.step(d);
+
+        // Try to move any blocking box in front.
+        if self
+            .get_tile(next_c)
+            .ok_or(anyhow!("box move out of bounds"))?
+            == Tile::Box
+        {
+            self.move_box(next_c, d)?;
+        }
+
+        // Check if empty space is available.
+        if self
+            .get_tile(next_c)
+            .ok_or(anyhow!("box move out of bounds"))?
+            == Tile::Empty
+        {
+            self.tiles[c] = Tile::Empty;
+            self.tiles[next_c] = Tile::Box;
+        }
+
+        Ok(())
+    }
+
+    fn move_robot(&mut self, d: Direction) -> Result<()> {
+        let next_c = self.robot.step(d);
+
+        // Try to move any blocking box in front.
+        if self
+            .get_tile(next_c)
+            .ok_or(anyhow!("robot move out of bounds"))?
+            == Tile::Box
+        {
+            self.move_box(next_c, d)?;
+        }
+
+        // Check if empty space is available.
+        if self
+            .get_tile(next_c)
+            .ok_or(anyhow!("robot move out of bounds"))?
+            == Tile::Empty
+        {
+            self.robot = next_c;
+        }
+
+        Ok(())
+    }
+
+    fn box_gps_sum(&self) -> usize {
+        self.tiles
+            .positions(|&tile| tile == Tile::Box)
+            .map(|c| 100 * c.y + c.x)
+            .sum()
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Warehouse, Vec<Direction>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (grid_raw, steps_raw) = input.split_once("\n\n").ok_or(anyhow!("invalid input"))?;
+
+        let warehouse = Warehouse::from_str(grid_raw)?;
+        let steps = steps_raw
+            .chars()
+            .filter(|c| *c != '\n') // ignore newlines
+            .map(|c| Direction::from_char(c).ok_or(anyhow!("invalid direction char: {c}")))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((warehouse, steps))
+    }
+
+    fn part1((warehouse, steps): &Self::Input) -> Result<Answer> {
+        let mut warehouse = warehouse.clone();
+        for &step in steps {
+            warehouse.move_robot(step)?;
+        }
+
+        Ok(warehouse.box_gps_sum().into())
+    }
+}
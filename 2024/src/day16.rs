@@ -0,0 +1,130 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    geometry::{Coord, Direction},
+    grid::Grid,
+    search::{dial, dijkstra_all, DenseStateSpace, Path, ShortestPaths, StateSpace},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Position {
+    coord: Coord,
+    direction: Direction,
+}
+
+impl Position {
+    fn move_forward(&self) -> Self {
+        Self {
+            coord: self.coord.step(self.direction),
+            direction: self.direction,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    start: Position,
+    end: Coord,
+    is_wall: Grid<bool>,
+}
+
+impl Maze {
+    fn from_str(s: &str) -> Result<Self> {
+        let grid = Grid::parse_chars(s)?;
+        let start = Position {
+            coord: grid.find_marker('S')?,
+            direction: Direction::Right,
+        };
+        let end = grid.find_marker('E')?;
+        let is_wall = grid.try_map(|&c| match c {
+            '#' => Ok(true),
+            '.' | 'S' | 'E' => Ok(false),
+            _ => Err(anyhow!("invalid input character: {c}")),
+        })?;
+
+        Ok(Self {
+            start,
+            end,
+            is_wall,
+        })
+    }
+
+    /// The cheapest route from the start to the end, turn by turn.
+    fn shortest_path(&self) -> Option<Path<Position, u32>> {
+        assert!(!self.is_wall[self.start.coord]);
+        assert!(!self.is_wall[self.end]);
+
+        dial(self, [self.start])
+    }
+
+    /// Every cheapest route from the start to the end.
+    fn all_shortest_paths(&self) -> Option<ShortestPaths<Position, u32>> {
+        assert!(!self.is_wall[self.start.coord]);
+        assert!(!self.is_wall[self.end]);
+
+        dijkstra_all(self, [self.start])
+    }
+}
+
+impl StateSpace for Maze {
+    type State = Position;
+    type Cost = u32;
+
+    fn successors(&self, position: &Position) -> impl Iterator<Item = (Position, u32)> {
+        let forward = position.move_forward();
+        let turns = position.direction.perpendicular().map(|direction| {
+            let turned = Position {
+                coord: position.coord,
+                direction,
+            };
+            (turned, 1_000)
+        });
+        (!self.is_wall[forward.coord])
+            .then_some((forward, 1))
+            .into_iter()
+            .chain(turns)
+    }
+
+    fn is_goal(&self, position: &Position) -> bool {
+        position.coord == self.end
+    }
+
+    fn heuristic(&self, position: &Position) -> u32 {
+        u32::try_from(position.coord.manhattan(self.end)).unwrap_or(0)
+    }
+}
+
+impl DenseStateSpace for Maze {
+    fn state_count(&self) -> usize {
+        self.is_wall.width() * self.is_wall.height() * 4
+    }
+
+    fn index(&self, position: &Position) -> usize {
+        let Position { coord, direction } = position;
+        (coord.y * self.is_wall.width() + coord.x) * 4 + direction.index()
+    }
+
+    fn max_step(&self) -> u32 {
+        1_000
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Maze::from_str(input)
+    }
+
+    fn part1(maze: &Self::Input) -> Result<Answer> {
+        let path = maze.shortest_path().ok_or(anyhow!("no path found"))?;
+        Ok(path.cost.into())
+    }
+
+    fn part2(maze: &Self::Input) -> Result<Answer> {
+        let paths = maze.all_shortest_paths().ok_or(anyhow!("no path found"))?;
+        Ok(paths.project(|position| position.coord).len().into())
+    }
+}
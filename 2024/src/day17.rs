@@ -0,0 +1,131 @@
+use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
+use regex::Regex;
+
+pub struct Computer {
+    a: u64,
+    b: u64,
+    c: u64,
+    program: Vec<u64>,
+}
+
+fn run(mut a: u64, mut b: u64, mut c: u64, program: &[u64]) -> Result<Vec<u64>> {
+    let mut outputs = vec![];
+
+    let mut pointer = 0;
+    while pointer < program.len() - 1 {
+        let opcode = program[pointer];
+        let literal_operand = program[pointer + 1];
+        let combo_operand = match literal_operand {
+            0..=3 => literal_operand,
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => return Err(anyhow!("invalid combo operand")),
+        };
+
+        match opcode {
+            0 => {
+                let numerator = a;
+                let denumerator = 2u64.pow(combo_operand.try_into()?);
+                a = numerator / denumerator;
+            }
+            1 => {
+                b ^= literal_operand;
+            }
+            2 => {
+                b = combo_operand % 8;
+            }
+            3 => {
+                if a != 0 {
+                    pointer = literal_operand.try_into()?;
+                    continue;
+                }
+            }
+            4 => {
+                b ^= c;
+            }
+            5 => {
+                let output = combo_operand % 8;
+                outputs.push(output);
+            }
+            6 => {
+                let numerator = a;
+                let denumerator = 2u64.pow(combo_operand.try_into()?);
+                b = numerator / denumerator;
+            }
+            7 => {
+                let numerator = a;
+                let denumerator = 2u64.pow(combo_operand.try_into()?);
+                c = numerator / denumerator;
+            }
+            _ => return Err(anyhow!("invalid opcode")),
+        }
+
+        pointer += 2;
+    }
+
+    Ok(outputs)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Computer;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = Regex::new(
+            r"Register A: ([0-9]+)\nRegister B: ([0-9]+)\nRegister C: ([0-9]+)\n\nProgram: ([0-9,]+)",
+        )?;
+
+        let caps = re.captures(input).ok_or(anyhow!("invalid input"))?;
+        let program = caps[4]
+            .split(',')
+            .map(str::parse)
+            .collect::<Result<Vec<u64>, _>>()?;
+
+        Ok(Computer {
+            a: caps[1].parse()?,
+            b: caps[2].parse()?,
+            c: caps[3].parse()?,
+            program,
+        })
+    }
+
+    fn part1(computer: &Self::Input) -> Result<Answer> {
+        let outputs = run(computer.a, computer.b, computer.c, &computer.program)?;
+        let result = outputs
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        Ok(result.into())
+    }
+
+    fn part2(computer: &Self::Input) -> Result<Answer> {
+        // The program loops, printing one value and dropping the lowest three
+        // bits of A each time, until A is 0. So the last output only depends
+        // on the highest three bits of A, and A can be rebuilt three bits at a
+        // time from the end of the program.
+        let program = &computer.program;
+        let mut candidates = vec![0];
+        for start in (0..program.len()).rev() {
+            let mut next = Vec::new();
+            for a in candidates {
+                for bits in 0..8 {
+                    let a = a << 3 | bits;
+                    if run(a, computer.b, computer.c, program)? == program[start..] {
+                        next.push(a);
+                    }
+                }
+            }
+            candidates = next;
+        }
+
+        let result: u64 = candidates
+            .into_iter()
+            .min()
+            .ok_or(anyhow!("no value for register A outputs the program"))?;
+        Ok(result.into())
+    }
+}
@@ -1,9 +1,8 @@
-#![warn(clippy::pedantic)]
-
 use anyhow::Result;
+use aoc_common::solution::{Answer, Solution};
 
 #[derive(Debug)]
-struct Report {
+pub struct Report {
     levels: Vec<i32>,
 }
 
@@ -57,20 +56,25 @@ impl Report {
     }
 }
 
-fn main() -> Result<()> {
-    let file = std::fs::read_to_string("inputs/2-input.txt")?;
+pub struct Day2;
 
-    let reports = file
-        .lines()
-        .map(Report::from_str)
-        .collect::<Result<Vec<_>>>()?;
+impl Solution for Day2 {
+    type Input = Vec<Report>;
 
-    let result = reports
-        .iter()
-        .filter(|r| r.is_safe_with_problem_dampener())
-        .count();
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(Report::from_str).collect()
+    }
 
-    println!("result: {result}");
+    fn part1(reports: &Self::Input) -> Result<Answer> {
+        let result = reports.iter().filter(|r| r.is_safe()).count();
+        Ok(result.into())
+    }
 
-    Ok(())
+    fn part2(reports: &Self::Input) -> Result<Answer> {
+        let result = reports
+            .iter()
+            .filter(|r| r.is_safe_with_problem_dampener())
+            .count();
+        Ok(result.into())
+    }
 }
@@ -0,0 +1,55 @@
+use anyhow::Result;
+use aoc_common::solution::{Answer, Solution};
+use regex::Regex;
+
+pub enum Instruction {
+    Do,
+    Dont,
+    Mul(i32, i32),
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = Regex::new(r"(?:don't\(\))|(?:do\(\))|(?:mul\(([0-9]{1,3}),([0-9]{1,3})\))")?;
+        re.captures_iter(input)
+            .map(|m| match &m[0] {
+                "do()" => Ok(Instruction::Do),
+                "don't()" => Ok(Instruction::Dont),
+                _ => Ok(Instruction::Mul(m[1].parse()?, m[2].parse()?)),
+            })
+            .collect()
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer> {
+        let mut result = 0;
+        for instruction in instructions {
+            if let Instruction::Mul(a, b) = instruction {
+                result += a * b;
+            }
+        }
+
+        Ok(result.into())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer> {
+        let mut result = 0;
+        let mut future_enabled = true;
+        for instruction in instructions {
+            match instruction {
+                Instruction::Do => future_enabled = true,
+                Instruction::Dont => future_enabled = false,
+                Instruction::Mul(a, b) => {
+                    if future_enabled {
+                        result += a * b;
+                    }
+                }
+            }
+        }
+
+        Ok(result.into())
+    }
+}
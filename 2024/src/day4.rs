@@ -0,0 +1,81 @@
+use anyhow::Result;
+use aoc_common::{
+    geometry::{Coord, Direction8, Step},
+    grid::Grid,
+    solution::{Answer, Solution},
+};
+
+// Count the occurrences of a word starting at a coordinate.
+// Looks in all 8 directions.
+fn count_word_occs_at(grid: &Grid<char>, coord: Coord, word: &str) -> usize {
+    Direction8::ALL
+        .into_iter()
+        .filter(|d| {
+            let (dx, dy) = d.delta();
+            (0..).zip(word.chars()).all(|(i, c)| {
+                coord
+                    .offset(i * dx, i * dy)
+                    .is_some_and(|coord| grid.get(coord) == Some(&c))
+            })
+        })
+        .count()
+}
+
+// Count the occurrences of a word in the grid. Occurrences can be
+// horizontal, vertical, diagonal, and backwards.
+fn count_word_occs(grid: &Grid<char>, word: &str) -> usize {
+    grid.coords()
+        .map(|coord| count_word_occs_at(grid, coord, word))
+        .sum()
+}
+
+// Check whether the core of an x-word is at a coordinate.
+fn is_x_word_core(grid: &Grid<char>, coord: Coord, word: [char; 3]) -> bool {
+    // The core has to be the middle letter.
+    if grid.get(coord) != Some(&word[1]) {
+        return false;
+    }
+
+    // Both diagonals through the core should hold the first and third letter,
+    // in either order. If we are on the edge, no x-word can be formed.
+    for start in [Direction8::NW, Direction8::NE] {
+        let end = start.reverse();
+        let start = grid.step(coord, start).map(|c| grid[c]);
+        let end = grid.step(coord, end).map(|c| grid[c]);
+        match (start, end) {
+            (Some(start), Some(end)) => {
+                if !((start == word[0] && end == word[2]) || (start == word[2] && end == word[0])) {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+// Count the occurrences of an x-word in the grid.
+fn count_x_words(grid: &Grid<char>, word: [char; 3]) -> usize {
+    grid.coords()
+        .filter(|&coord| is_x_word_core(grid, coord, word))
+        .count()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_chars(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(count_word_occs(grid, "XMAS").into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        Ok(count_x_words(grid, ['M', 'A', 'S']).into())
+    }
+}
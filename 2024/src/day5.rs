@@ -0,0 +1,66 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    order::PartialOrder,
+    solution::{Answer, Solution},
+};
+
+pub struct PrintQueue {
+    rules: PartialOrder<u32>,
+    updates: Vec<Vec<u32>>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = PrintQueue;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (ordering_rules_raw, updates_raw) =
+            input.split_once("\n\n").ok_or(anyhow!("invalid input"))?;
+
+        // Collect the ordering rules into a list of pairs.
+        let ordering_rules = ordering_rules_raw
+            .lines()
+            .map(|line| {
+                let (left_raw, right_raw) = line.split_once('|').ok_or(anyhow!("invalid input"))?;
+                Ok((left_raw.parse()?, right_raw.parse()?))
+            })
+            .collect::<Result<Vec<(u32, u32)>>>()?;
+
+        // Collect the updates into a vector of vectors.
+        let updates = updates_raw
+            .lines()
+            .map(|line| {
+                line.split(',')
+                    .map(|s| Ok(s.parse::<u32>()?))
+                    .collect::<Result<Vec<u32>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(PrintQueue {
+            rules: PartialOrder::new(ordering_rules),
+            updates,
+        })
+    }
+
+    fn part1(queue: &Self::Input) -> Result<Answer> {
+        let result: u32 = queue
+            .updates
+            .iter()
+            .filter(|update| queue.rules.is_ordered(update))
+            .map(|update| update[update.len() / 2])
+            .sum();
+        Ok(result.into())
+    }
+
+    fn part2(queue: &Self::Input) -> Result<Answer> {
+        let mut result = 0;
+        for update in &queue.updates {
+            if !queue.rules.is_ordered(update) {
+                let update = queue.rules.sort(update)?;
+                result += update[update.len() / 2];
+            }
+        }
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    cycle::brent,
+    geometry::{Coord, Direction},
+    grid::Grid,
+    solution::{Answer, Solution},
+};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct DirectedPosition {
+    p: Coord,
+    d: Direction,
+}
+
+impl DirectedPosition {
+    fn turn_right(&mut self) {
+        self.d = self.d.clockwise();
+    }
+}
+
+fn parse_grid(s: &str) -> Result<(Grid<bool>, DirectedPosition)> {
+    let grid = Grid::parse_chars(s)?;
+
+    let mut start: Option<DirectedPosition> = None;
+    for (p, &c) in grid.iter() {
+        let Some(d) = Direction::from_char(c) else {
+            continue;
+        };
+        if start.is_some() {
+            return Err(anyhow!("multiple starting points found"));
+        }
+        start = Some(DirectedPosition { p, d });
+    }
+
+    let is_obstruction = grid.try_map(|&c| match c {
+        '#' => Ok(true),
+        '.' | '^' | 'v' | '<' | '>' => Ok(false),
+        _ => Err(anyhow!("invalid character in grid")),
+    })?;
+
+    Ok((
+        is_obstruction,
+        start.ok_or_else(|| anyhow!("no starting point found"))?,
+    ))
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    is_obstruction: Grid<bool>,
+    current: DirectedPosition,
+}
+
+impl State {
+    /// Compute the position ahead of the current position. If the position ahead
+    /// is out of bounds, return None.
+    fn position_ahead(&self) -> Option<DirectedPosition> {
+        let p = self.is_obstruction.step(self.current.p, self.current.d)?;
+        Some(DirectedPosition { p, ..self.current })
+    }
+
+    /// Walk until we leave the grid and count the distinct positions visited.
+    fn count_visited(mut self) -> usize {
+        let mut visited = HashSet::from([self.current.p]);
+        while let Some(ahead) = self.position_ahead() {
+            if self.is_obstruction[ahead.p] {
+                self.current.turn_right();
+            } else {
+                self.current = ahead;
+                visited.insert(ahead.p);
+            }
+        }
+        visited.len()
+    }
+
+    /// The guard's position after its next move or turn from `current`, or
+    /// `None` if it leaves the grid.
+    fn step_from(&self, current: DirectedPosition) -> Option<DirectedPosition> {
+        let p = self.is_obstruction.step(current.p, current.d)?;
+        if self.is_obstruction[p] {
+            let mut turned = current;
+            turned.turn_right();
+            Some(turned)
+        } else {
+            Some(DirectedPosition { p, ..current })
+        }
+    }
+
+    /// Check if walking from the current position results in a loop. If it does,
+    /// return true. If we walk out of bounds, return false.
+    fn results_in_loop(&self) -> bool {
+        // Having left the grid is a state that steps to itself. Otherwise the
+        // guard always moves or turns, so only a loop has a longer period.
+        let cycle = brent(Some(self.current), |current| self.step_from((*current)?));
+        cycle.period > 1
+    }
+
+    fn count_extra_obstruction_loops(mut self, banned_positions: &HashSet<Coord>) -> usize {
+        assert!(!self.results_in_loop());
+
+        let mut loop_obstructions: HashSet<Coord> = HashSet::new();
+        let mut visited_directed = HashSet::from([self.current]);
+        let mut visited_undirected = HashSet::from([self.current.p]);
+
+        while let Some(ahead) = self.position_ahead() {
+            if self.is_obstruction[ahead.p] {
+                self.current.turn_right();
+            } else {
+                // Try adding an obstruction at the position ahead and see if it results in a loop.
+                // This is only valid if the position is not banned or previously visited. If a
+                // previously visited position is obstructed, the current position is not necessarily
+                // reachable, which would result in a false positive.
+                if !banned_positions.contains(&ahead.p) && !visited_undirected.contains(&ahead.p) {
+                    let mut with_extra = self.clone();
+                    with_extra.is_obstruction[ahead.p] = true;
+
+                    if with_extra.results_in_loop() {
+                        loop_obstructions.insert(ahead.p);
+                    }
+                }
+
+                self.current = ahead;
+            }
+
+            visited_directed.insert(self.current);
+            visited_undirected.insert(self.current.p);
+        }
+
+        loop_obstructions.len()
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = State;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (is_obstruction, start) = parse_grid(input)?;
+        Ok(State {
+            is_obstruction,
+            current: start,
+        })
+    }
+
+    fn part1(state: &Self::Input) -> Result<Answer> {
+        Ok(state.clone().count_visited().into())
+    }
+
+    fn part2(state: &Self::Input) -> Result<Answer> {
+        let start = state.current.p;
+        let result = state
+            .clone()
+            .count_extra_obstruction_loops(&HashSet::from([start]));
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
+
+fn concatenation_operator(a: u64, b: u64) -> u64 {
+    let b_digits = b.ilog10() + 1;
+    let a_with_zeroes = a * 10u64.pow(b_digits);
+    a_with_zeroes + b
+}
+
+fn is_possible_with_operators_rec(
+    target: u64,
+    current: u64,
+    values: &[u64],
+    with_concatenation: bool,
+) -> bool {
+    // We can kill this branch early if we know we've gone over the target.
+    if current > target {
+        return false;
+    }
+
+    if values.is_empty() {
+        return target == current;
+    }
+
+    let value = values[0];
+    let rest = &values[1..];
+
+    is_possible_with_operators_rec(target, current * value, rest, with_concatenation)
+        || is_possible_with_operators_rec(target, current + value, rest, with_concatenation)
+        || (with_concatenation
+            && is_possible_with_operators_rec(
+                target,
+                concatenation_operator(current, value),
+                rest,
+                with_concatenation,
+            ))
+}
+
+fn calibration_result(equations: &[(u64, Vec<u64>)], with_concatenation: bool) -> u64 {
+    equations
+        .iter()
+        .filter(|(total, values)| {
+            is_possible_with_operators_rec(*total, 0, values, with_concatenation)
+        })
+        .map(|(total, _)| total)
+        .sum()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                let (total_raw, values_raw) = line
+                    .split_once(": ")
+                    .ok_or_else(|| anyhow!("invalid equation line"))?;
+
+                let total = total_raw.parse::<u64>()?;
+                let values = values_raw
+                    .split_whitespace()
+                    .map(|v| Ok(v.parse::<u64>()?))
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok((total, values))
+            })
+            .collect()
+    }
+
+    fn part1(equations: &Self::Input) -> Result<Answer> {
+        Ok(calibration_result(equations, false).into())
+    }
+
+    fn part2(equations: &Self::Input) -> Result<Answer> {
+        Ok(calibration_result(equations, true).into())
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::Result;
+use aoc_common::{
+    geometry::{Coord, ICoord},
+    grid::Grid,
+    solution::{Answer, Solution},
+};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub struct Map {
+    width: usize,
+    height: usize,
+    antennas: HashMap<char, Vec<ICoord>>,
+}
+
+impl Map {
+    fn from_str(s: &str) -> Result<Self> {
+        let grid = Grid::parse_chars(s)?;
+
+        let mut antennas: HashMap<char, Vec<ICoord>> = HashMap::new();
+        for (pos, &c) in grid.iter() {
+            if c != '.' {
+                antennas.entry(c).or_default().push(ICoord::try_from(pos)?);
+            }
+        }
+
+        Ok(Self {
+            width: grid.width(),
+            height: grid.height(),
+            antennas,
+        })
+    }
+
+    fn within_bounds(&self, coord: ICoord) -> bool {
+        Coord::try_from(coord).is_ok_and(|c| c.x < self.width && c.y < self.height)
+    }
+
+    /// Get the two antinodes caused by two antennas of the same kind. Does not
+    /// check if the antinodes are within bounds.
+    fn get_antinode_pair(a: ICoord, b: ICoord) -> (ICoord, ICoord) {
+        let d = b - a;
+        (b + d, a - d)
+    }
+
+    /// Get all antinodes caused by any pair of antennas of the same kind. The
+    /// coords are all within bounds and returned as a set.
+    fn get_all_antinodes(&self) -> HashSet<ICoord> {
+        let mut result = HashSet::new();
+
+        for coords in self.antennas.values() {
+            for (a, b) in coords.iter().tuple_combinations() {
+                let (first, second) = Self::get_antinode_pair(*a, *b);
+                if self.within_bounds(first) {
+                    result.insert(first);
+                }
+                if self.within_bounds(second) {
+                    result.insert(second);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Get the resonant antinodes caused by two antennas of the same kind. The coords
+    /// returned are all guaranteed to be within bounds.
+    fn get_antinodes_for_pair(&'_ self, a: ICoord, b: ICoord) -> impl Iterator<Item = ICoord> + '_ {
+        let d = b - a;
+
+        let forward = (0..)
+            .map(move |n| b + d * n)
+            .take_while(|c| self.within_bounds(*c));
+
+        let backward = (0..)
+            .map(move |n| a - d * n)
+            .take_while(|c| self.within_bounds(*c));
+
+        forward.chain(backward)
+    }
+
+    /// Get all antinodes caused by any pair of antennas of the same kind,
+    /// taking resonant harmonics into account.
+    fn get_all_resonant_antinodes(&self) -> HashSet<ICoord> {
+        let mut result = HashSet::new();
+
+        for coords in self.antennas.values() {
+            for (a, b) in coords.iter().tuple_combinations() {
+                result.extend(self.get_antinodes_for_pair(*a, *b));
+            }
+        }
+
+        result
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Map::from_str(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer> {
+        Ok(map.get_all_antinodes().len().into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer> {
+        Ok(map.get_all_resonant_antinodes().len().into())
+    }
+}
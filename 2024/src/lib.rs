@@ -0,0 +1,46 @@
+#![warn(clippy::pedantic)]
+#![allow(clippy::missing_errors_doc)]
+
+use aoc_common::solution::Year;
+
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+pub const YEAR: Year = Year {
+    year: 2024,
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    days: &[
+        (1, &day1::Day1),
+        (2, &day2::Day2),
+        (3, &day3::Day3),
+        (4, &day4::Day4),
+        (5, &day5::Day5),
+        (6, &day6::Day6),
+        (7, &day7::Day7),
+        (8, &day8::Day8),
+        (9, &day9::Day9),
+        (10, &day10::Day10),
+        (11, &day11::Day11),
+        (12, &day12::Day12),
+        (13, &day13::Day13),
+        (14, &day14::Day14),
+        (15, &day15::Day15),
+        (16, &day16::Day16),
+        (17, &day17::Day17),
+    ],
+};
@@ -0,0 +1,83 @@
+use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
+use regex::Regex;
+
+const DIAL_SIZE: i32 = 100;
+const START_POSITION: i32 = 50;
+
+#[derive(Debug)]
+pub struct Instruction {
+    clockwise: bool,
+    count: i32,
+}
+
+impl Instruction {
+    fn from_str(s: &str) -> Result<Self> {
+        let re = Regex::new(r"^([RL])(\d+)$")?;
+        let caps = re.captures(s).ok_or(anyhow!("invalid instruction: {s}"))?;
+        let clockwise = match &caps[1] {
+            "R" => true,
+            "L" => false,
+            _ => return Err(anyhow!("invalid direction: {}", &caps[1])),
+        };
+        let count = caps[2].parse()?;
+        Ok(Self { clockwise, count })
+    }
+}
+
+fn turn(position: &mut i32, instruction: &Instruction) {
+    let count = instruction.count % DIAL_SIZE;
+    if instruction.clockwise {
+        *position += count;
+    } else {
+        *position -= count;
+    }
+    if *position >= DIAL_SIZE {
+        *position -= DIAL_SIZE;
+    } else if *position < 0 {
+        *position += DIAL_SIZE;
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(Instruction::from_str).collect()
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer> {
+        let mut position = START_POSITION;
+        let mut result = 0;
+        for instruction in instructions {
+            turn(&mut position, instruction);
+            if position == 0 {
+                result += 1;
+            }
+        }
+
+        Ok(result.into())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer> {
+        let mut position = START_POSITION;
+        let mut result = 0;
+        for instruction in instructions {
+            let distance_to_zero = if instruction.clockwise {
+                DIAL_SIZE - position
+            } else {
+                position
+            };
+            if instruction.count >= distance_to_zero {
+                result += i32::from(distance_to_zero > 0);
+                result += (instruction.count - distance_to_zero) / DIAL_SIZE;
+            }
+
+            turn(&mut position, instruction);
+        }
+
+        Ok(result.into())
+    }
+}
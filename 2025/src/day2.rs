@@ -1,6 +1,5 @@
-#![warn(clippy::pedantic)]
-
 use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
 use regex::Regex;
 
 /// Returns the number of digits in the number `n`. Assumes `n` is greater than 0.
@@ -38,7 +37,20 @@ fn chunked_digit_iter<'a>(mut n: u64, chunk_size: u32) -> impl Iterator<Item = u
     })
 }
 
-fn is_invalid(product_id: u64) -> bool {
+/// Whether a product ID is a sequence of digits repeated exactly twice.
+fn is_repeated_twice(product_id: u64) -> bool {
+    let digits = number_of_digits(product_id);
+    if !digits.is_multiple_of(2) {
+        return false;
+    }
+
+    let middle = digits / 2;
+    let (first_half, second_half) = split_digits_at(product_id, middle);
+    first_half == second_half
+}
+
+/// Whether a product ID is a sequence of digits repeated at least twice.
+fn is_repeated(product_id: u64) -> bool {
     let digits = number_of_digits(product_id);
 
     for repetitions in 2..=digits {
@@ -57,27 +69,39 @@ fn is_invalid(product_id: u64) -> bool {
     false
 }
 
-fn main() -> Result<()> {
-    let file = std::fs::read_to_string("inputs/2-input.txt")?;
-
-    let mut result = 0;
-    let range_re = Regex::new(r"(\d+)-(\d+)")?;
-    for raw_range in file.split(',') {
-        let caps = range_re
-            .captures(raw_range)
-            .ok_or_else(|| anyhow!("invalid range: {raw_range}"))?;
-        let start: u64 = caps[1].parse()?;
-        let end: u64 = caps[2].parse()?;
-
-        for product_id in start..=end {
-            if is_invalid(product_id) {
-                result += product_id;
-            }
-        }
+fn sum_matching(ranges: &[(u64, u64)], pred: impl Fn(u64) -> bool) -> u64 {
+    ranges
+        .iter()
+        .flat_map(|&(start, end)| start..=end)
+        .filter(|&product_id| pred(product_id))
+        .sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let range_re = Regex::new(r"(\d+)-(\d+)")?;
+        input
+            .split(',')
+            .map(|raw_range| {
+                let caps = range_re
+                    .captures(raw_range)
+                    .ok_or_else(|| anyhow!("invalid range: {raw_range}"))?;
+                Ok((caps[1].parse()?, caps[2].parse()?))
+            })
+            .collect()
     }
 
-    println!("{result}");
-    Ok(())
+    fn part1(ranges: &Self::Input) -> Result<Answer> {
+        Ok(sum_matching(ranges, is_repeated_twice).into())
+    }
+
+    fn part2(ranges: &Self::Input) -> Result<Answer> {
+        Ok(sum_matching(ranges, is_repeated).into())
+    }
 }
 
 #[cfg(test)]
@@ -106,10 +130,10 @@ mod tests {
     }
 
     #[test]
-    fn test_is_invalid() {
-        assert!(is_invalid(11));
-        assert!(is_invalid(1212));
-        assert!(is_invalid(123_123));
-        assert!(is_invalid(9999));
+    fn test_is_repeated() {
+        assert!(is_repeated(11));
+        assert!(is_repeated(1212));
+        assert!(is_repeated(123_123));
+        assert!(is_repeated(9999));
     }
 }
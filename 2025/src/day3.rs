@@ -1,6 +1,5 @@
-#![warn(clippy::pedantic)]
-
 use anyhow::Result;
+use aoc_common::solution::{Answer, Solution};
 
 fn max_joltage_rec(bank: &[char], acc: &mut Vec<char>, budget: usize) {
     assert!(bank.len() >= budget);
@@ -28,17 +27,24 @@ fn max_joltage(bank: &[char], budget: usize) -> u64 {
     acc.iter().collect::<String>().parse().unwrap()
 }
 
-fn main() -> Result<()> {
-    let file = std::fs::read_to_string("inputs/3-input.txt")?;
+fn total_joltage(banks: &[Vec<char>], budget: usize) -> u64 {
+    banks.iter().map(|bank| max_joltage(bank, budget)).sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Vec<char>>;
 
-    let result = file
-        .lines()
-        .map(|line| {
-            let bank = line.chars().collect::<Vec<_>>();
-            max_joltage(&bank, 12)
-        })
-        .sum::<u64>();
-    println!("{result}");
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    fn part1(banks: &Self::Input) -> Result<Answer> {
+        Ok(total_joltage(banks, 2).into())
+    }
 
-    Ok(())
+    fn part2(banks: &Self::Input) -> Result<Answer> {
+        Ok(total_joltage(banks, 12).into())
+    }
 }
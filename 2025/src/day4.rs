@@ -0,0 +1,67 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    geometry::Coord,
+    grid::Grid,
+    solution::{Answer, Solution},
+};
+
+fn surrounding_count(grid: &Grid<bool>, c: Coord) -> usize {
+    grid.neighbours8(c).filter(|&n| grid[n]).count()
+}
+
+fn is_reachable(grid: &Grid<bool>, c: Coord) -> bool {
+    surrounding_count(grid, c) < 4
+}
+
+fn remove_reachable(grid: &mut Grid<bool>) -> usize {
+    let to_remove = grid
+        .positions(|&cell| cell)
+        .filter(|&c| is_reachable(grid, c))
+        .collect::<Vec<_>>();
+
+    let count = to_remove.len();
+    for c in to_remove {
+        grid[c] = false;
+    }
+
+    count
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, |c| {
+            if c == '@' {
+                Ok(true)
+            } else if c == '.' {
+                Ok(false)
+            } else {
+                Err(anyhow!("invalid char: {c}"))
+            }
+        })
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        let result = grid
+            .positions(|&cell| cell)
+            .filter(|&c| is_reachable(grid, c))
+            .count();
+        Ok(result.into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        let mut grid = grid.clone();
+        let mut result = 0;
+        loop {
+            let removed = remove_reachable(&mut grid);
+            if removed == 0 {
+                break;
+            }
+            result += removed;
+        }
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    interval::IntervalSet,
+    solution::{Answer, Solution},
+};
+
+pub struct Inventory {
+    fresh: IntervalSet<u64>,
+    ingredients: Vec<u64>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (ranges_raw, ingredients_raw) = input
+            .split_once("\n\n")
+            .ok_or_else(|| anyhow!("no double newline in input"))?;
+
+        let ranges = ranges_raw
+            .lines()
+            .map(|line| {
+                let (start_raw, end_raw) = line
+                    .split_once('-')
+                    .ok_or_else(|| anyhow!("no dash in range string: {line}"))?;
+                Ok(start_raw.parse()?..=end_raw.parse()?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let ingredients = ingredients_raw
+            .lines()
+            .map(|line| {
+                let ingredient: u64 = line.parse()?;
+                Ok(ingredient)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Inventory {
+            fresh: IntervalSet::from_inclusive(ranges)?,
+            ingredients,
+        })
+    }
+
+    fn part1(inventory: &Self::Input) -> Result<Answer> {
+        let result = inventory
+            .ingredients
+            .iter()
+            .filter(|&&ingredient| inventory.fresh.contains(ingredient))
+            .count();
+        Ok(result.into())
+    }
+
+    fn part2(inventory: &Self::Input) -> Result<Answer> {
+        Ok(inventory.fresh.len().into())
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::{anyhow, Result};
+use aoc_common::solution::{Answer, Solution};
+
+/// The worksheet as text, since the two parts read the operands differently.
+pub struct Worksheet {
+    operand_lines: Vec<String>,
+    operator_line: String,
+}
+
+impl Worksheet {
+    fn operators(&self) -> Vec<&str> {
+        self.operator_line.split_whitespace().collect()
+    }
+}
+
+fn grand_total(operators: &[&str], operands: &[Vec<u64>]) -> Result<u64> {
+    let mut result = 0;
+    for (&op, nums) in operators.iter().zip(operands) {
+        let op_result: u64 = match op {
+            "+" => Ok(nums.iter().sum()),
+            "*" => Ok(nums.iter().product()),
+            _ => Err(anyhow!("invalid operator: {op}")),
+        }?;
+        result += op_result;
+    }
+    Ok(result)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = input.lines().map(str::to_owned).collect::<Vec<_>>();
+        if lines.len() < 2 {
+            return Err(anyhow!("expected operand lines and an operator line"));
+        }
+
+        let operator_line = lines.pop().unwrap();
+        Ok(Worksheet {
+            operand_lines: lines,
+            operator_line,
+        })
+    }
+
+    fn part1(worksheet: &Self::Input) -> Result<Answer> {
+        let operators = worksheet.operators();
+        let mut operands: Vec<Vec<u64>> = (0..operators.len()).map(|_| Vec::new()).collect();
+        for line in &worksheet.operand_lines {
+            let nums = line
+                .split_whitespace()
+                .map(|s| Ok(s.parse::<u64>()?))
+                .collect::<Result<Vec<_>>>()?;
+            if nums.len() != operators.len() {
+                return Err(anyhow!(
+                    "expected {} operands per line, got {}",
+                    operators.len(),
+                    nums.len()
+                ));
+            }
+            for (i, &num) in nums.iter().enumerate() {
+                operands[i].push(num);
+            }
+        }
+
+        Ok(grand_total(&operators, &operands)?.into())
+    }
+
+    fn part2(worksheet: &Self::Input) -> Result<Answer> {
+        let mut column_widths: Vec<usize> = Vec::from([1]);
+        for char in worksheet.operator_line.chars().skip(1) {
+            let last = column_widths.last_mut().unwrap();
+            if char.is_whitespace() {
+                *last += 1;
+            } else {
+                *last -= 1;
+                column_widths.push(1);
+            }
+        }
+
+        let operators = worksheet.operators();
+        let column_count = operators.len();
+        let mut operand_chars = (0..column_count)
+            .map(|i| {
+                (0..column_widths[i])
+                    .map(|_| Vec::new())
+                    .collect::<Vec<Vec<char>>>()
+            })
+            .collect::<Vec<Vec<Vec<char>>>>();
+
+        for line in &worksheet.operand_lines {
+            let mut chars = line.chars();
+            for (column_idx, column_width) in column_widths.iter().enumerate() {
+                for (subcolumn_idx, c) in chars.by_ref().take(*column_width).enumerate() {
+                    if !c.is_whitespace() {
+                        operand_chars[column_idx][subcolumn_idx].push(c);
+                    }
+                }
+                let _ = chars.next();
+            }
+        }
+
+        let operands = operand_chars
+            .into_iter()
+            .map(|subcolumns| {
+                subcolumns
+                    .into_iter()
+                    .filter(|chars| !chars.is_empty())
+                    .map(|chars| chars.into_iter().collect::<String>())
+                    .map(|s| Ok(s.parse::<u64>()?))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<Vec<u64>>>>()?;
+
+        Ok(grand_total(&operators, &operands)?.into())
+    }
+}
@@ -0,0 +1,24 @@
+#![warn(clippy::pedantic)]
+#![allow(clippy::missing_errors_doc)]
+
+use aoc_common::solution::Year;
+
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+
+pub const YEAR: Year = Year {
+    year: 2025,
+    inputs_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    days: &[
+        (1, &day1::Day1),
+        (2, &day2::Day2),
+        (3, &day3::Day3),
+        (4, &day4::Day4),
+        (5, &day5::Day5),
+        (6, &day6::Day6),
+    ],
+};
@@ -0,0 +1,141 @@
+use crate::InputSource;
+use anyhow::{anyhow, Context, Result};
+use aoc_common::solution::{Part, Year};
+use serde::Deserialize;
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// Expected answers of one part of a day.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PartAnswers {
+    /// The verified answer for the real input.
+    pub input: Option<String>,
+    /// Answers for the day's examples, keyed by example number.
+    #[serde(default)]
+    pub examples: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayAnswers {
+    pub a: Option<PartAnswers>,
+    pub b: Option<PartAnswers>,
+}
+
+impl DayAnswers {
+    pub fn part(&self, part: Part) -> Option<&PartAnswers> {
+        match part {
+            Part::A => self.a.as_ref(),
+            Part::B => self.b.as_ref(),
+        }
+    }
+}
+
+/// One solution run with a known answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u32,
+    pub part: Part,
+    pub source: InputSource,
+    pub expected: String,
+}
+
+/// The manifest of a year lives next to its inputs.
+pub fn manifest_path(year: &Year) -> PathBuf {
+    PathBuf::from(year.inputs_dir).join("answers.toml")
+}
+
+pub fn parse_manifest(s: &str) -> Result<Vec<Check>> {
+    let days: BTreeMap<String, DayAnswers> = toml::from_str(s)?;
+
+    let mut result = Vec::new();
+    for (day, answers) in &days {
+        let day = day
+            .parse()
+            .with_context(|| format!("invalid day '{day}'"))?;
+        for part in Part::ALL {
+            let Some(answers) = answers.part(part) else {
+                continue;
+            };
+            for (n, expected) in &answers.examples {
+                let n = n
+                    .parse()
+                    .with_context(|| format!("invalid example number '{n}' for day {day}"))?;
+                result.push(Check {
+                    day,
+                    part,
+                    source: InputSource::Example(n),
+                    expected: expected.clone(),
+                });
+            }
+            if let Some(expected) = &answers.input {
+                result.push(Check {
+                    day,
+                    part,
+                    source: InputSource::Default,
+                    expected: expected.clone(),
+                });
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Every answer recorded for a year. A year without a manifest has none.
+pub fn load_checks(year: &Year) -> Result<Vec<Check>> {
+    let path = manifest_path(year);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let s = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    parse_manifest(&s).with_context(|| format!("invalid manifest {}", path.display()))
+}
+
+/// Run a check and describe how it failed, if it did.
+pub fn verify(year: &Year, check: &Check) -> Option<String> {
+    let what = match check.source {
+        InputSource::Example(n) => format!("example {n}"),
+        _ => "input".to_owned(),
+    };
+    let name = format!("{} day {} part {} {what}", year.year, check.day, check.part);
+    let answer = crate::run(year.year, check.day, &[check.part], &check.source)
+        .and_then(|answers| answers.into_iter().next().ok_or(anyhow!("no answer")));
+    match answer {
+        Ok(answer) if answer.to_string() == check.expected => None,
+        Ok(answer) => Some(format!("{name}: expected {}, got {answer}", check.expected)),
+        Err(e) => Some(format!("{name}: {e:#}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let checks = parse_manifest(
+            r#"
+            [16]
+            a = { input = "7", examples = { 1 = "1", 2 = "2" } }
+            b = { examples = { 2 = "3" } }
+            "#,
+        )
+        .unwrap();
+        let sources = checks
+            .iter()
+            .map(|c| (c.day, c.part, c.source.clone(), c.expected.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sources,
+            [
+                (16, Part::A, InputSource::Example(1), "1"),
+                (16, Part::A, InputSource::Example(2), "2"),
+                (16, Part::A, InputSource::Default, "7"),
+                (16, Part::B, InputSource::Example(2), "3"),
+            ]
+        );
+        assert!(parse_manifest("[1]\nc = {}").is_err());
+        assert!(parse_manifest("[x]\na = {}").is_err());
+    }
+}
@@ -0,0 +1,288 @@
+use crate::{answers::load_checks, input_path, read_input, InputSource};
+use anyhow::{anyhow, Context, Result};
+use aoc_common::solution::{Part, Year};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+/// Summary of the durations of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        samples.sort_unstable();
+        let n = samples.len();
+        let (min, max) = (*samples.first()?, *samples.last()?);
+        let median = (samples[(n - 1) / 2] + samples[n / 2]) / 2;
+        Some(Self { median, min, max })
+    }
+}
+
+/// Timing of one stage of a day: parsing its input, or solving one part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub year: u32,
+    pub day: u32,
+    /// `parse`, `a` or `b`.
+    pub stage: String,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Measurement {
+    fn new(year: u32, day: u32, stage: String, timing: Timing) -> Self {
+        let nanos = |d: Duration| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX);
+        Self {
+            year,
+            day,
+            stage,
+            median_ns: nanos(timing.median),
+            min_ns: nanos(timing.min),
+            max_ns: nanos(timing.max),
+        }
+    }
+
+    fn key(&self) -> (u32, u32, &str) {
+        (self.year, self.day, &self.stage)
+    }
+}
+
+/// Run `f` the given number of times. Returns how long that took, and the
+/// result of the last run.
+fn time<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<(Timing, T)> {
+    let mut samples = Vec::with_capacity(iterations);
+    let mut result = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let value = f()?;
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+    Timing::from_samples(&mut samples)
+        .zip(result)
+        .ok_or(anyhow!("at least one iteration is needed"))
+}
+
+/// Time parsing and the given parts of a day on its real input. Each stage
+/// runs `iterations` times, and the parts share the last parsed input.
+pub fn bench_day(
+    year: &Year,
+    day: u32,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Vec<Measurement>> {
+    let solution = year
+        .day(day)
+        .ok_or_else(|| anyhow!("no solution for {} day {day}", year.year))?;
+    let path = input_path(year, day, Part::A, &InputSource::Default)?;
+    let input = read_input(path.as_deref())?;
+
+    let (timing, parsed) = time(iterations, || solution.parse_any(&input))?;
+    let mut result = vec![Measurement::new(year.year, day, "parse".to_owned(), timing)];
+    for &part in parts {
+        let (timing, _) = time(iterations, || {
+            solution
+                .solve_any(parsed.as_ref(), part)
+                .with_context(|| format!("{} day {day} part {part}", year.year))
+        })?;
+        result.push(Measurement::new(year.year, day, part.to_string(), timing));
+    }
+    Ok(result)
+}
+
+/// The days and parts to benchmark. Without a day, that is every part with a
+/// verified answer for its real input, which leaves out unsolved parts and
+/// ones too slow to have finished.
+pub fn select_parts(
+    year: &Year,
+    day: Option<u32>,
+    part: Option<Part>,
+) -> Result<BTreeMap<u32, Vec<Part>>> {
+    let parts = part.map_or(Part::ALL.to_vec(), |p| vec![p]);
+    if let Some(day) = day {
+        return Ok(BTreeMap::from([(day, parts)]));
+    }
+
+    let mut result: BTreeMap<u32, Vec<Part>> = BTreeMap::new();
+    for check in load_checks(year)? {
+        if check.source == InputSource::Default && parts.contains(&check.part) {
+            result.entry(check.day).or_default().push(check.part);
+        }
+    }
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub measurements: Vec<Measurement>,
+}
+
+/// Regressions are medians more than `threshold` percent slower than their
+/// baseline.
+#[derive(Debug, Clone, Copy)]
+pub struct Comparison<'a> {
+    pub baseline: &'a Report,
+    pub threshold: f64,
+}
+
+impl Comparison<'_> {
+    fn find(&self, measurement: &Measurement) -> Option<&Measurement> {
+        self.baseline
+            .measurements
+            .iter()
+            .find(|m| m.key() == measurement.key())
+    }
+
+    /// Relative change of the median in percent.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn change(&self, measurement: &Measurement) -> Option<f64> {
+        let baseline = self.find(measurement)?;
+        if baseline.median_ns == 0 {
+            return None;
+        }
+        let delta = measurement.median_ns as f64 - baseline.median_ns as f64;
+        Some(delta / baseline.median_ns as f64 * 100.0)
+    }
+
+    pub fn is_regression(&self, measurement: &Measurement) -> bool {
+        self.change(measurement)
+            .is_some_and(|change| change > self.threshold)
+    }
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+impl Report {
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(s: &str) -> Result<Self> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    pub fn regressions<'a>(&'a self, comparison: &Comparison) -> Vec<&'a Measurement> {
+        self.measurements
+            .iter()
+            .filter(|m| comparison.is_regression(m))
+            .collect()
+    }
+
+    pub fn to_markdown(&self, comparison: Option<&Comparison>) -> String {
+        let mut result = String::new();
+        if comparison.is_some() {
+            result.push_str("| Year | Day | Stage | Median | Min | Max | Baseline | Change |\n");
+            result.push_str("|-----:|----:|-------|-------:|----:|----:|---------:|-------:|\n");
+        } else {
+            result.push_str("| Year | Day | Stage | Median | Min | Max |\n");
+            result.push_str("|-----:|----:|-------|-------:|----:|----:|\n");
+        }
+
+        for m in &self.measurements {
+            let _ = write!(
+                result,
+                "| {} | {} | {} | {} | {} | {} |",
+                m.year,
+                m.day,
+                m.stage,
+                format_ns(m.median_ns),
+                format_ns(m.min_ns),
+                format_ns(m.max_ns)
+            );
+            if let Some(comparison) = comparison {
+                let baseline = comparison
+                    .find(m)
+                    .map_or("-".to_owned(), |b| format_ns(b.median_ns));
+                let change = match comparison.change(m) {
+                    Some(change) if comparison.is_regression(m) => {
+                        format!("**{change:+.1}%** regression")
+                    }
+                    Some(change) => format!("{change:+.1}%"),
+                    None => "-".to_owned(),
+                };
+                let _ = write!(result, " {baseline} | {change} |");
+            }
+            result.push('\n');
+        }
+
+        if let Some(comparison) = comparison {
+            let count = self.regressions(comparison).len();
+            let _ = write!(
+                result,
+                "\n{count} regression(s) over {}%.\n",
+                comparison.threshold
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(stage: &str, median_ns: u64) -> Measurement {
+        Measurement {
+            year: 2024,
+            day: 6,
+            stage: stage.to_owned(),
+            median_ns,
+            min_ns: median_ns,
+            max_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn test_timing() {
+        let ms = Duration::from_millis;
+        let timing = Timing::from_samples(&mut [ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!(timing.median, ms(3));
+        assert_eq!(timing.min, ms(1));
+        assert_eq!(timing.max, ms(5));
+        let timing = Timing::from_samples(&mut [ms(4), ms(1), ms(2), ms(9)]).unwrap();
+        assert_eq!(timing.median, ms(3));
+        assert_eq!(Timing::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = Report {
+            measurements: vec![measurement("parse", 100), measurement("a", 100)],
+        };
+        let report = Report {
+            measurements: vec![
+                measurement("parse", 105),
+                measurement("a", 150),
+                measurement("b", 100),
+            ],
+        };
+        let comparison = Comparison {
+            baseline: &baseline,
+            threshold: 10.0,
+        };
+        assert_eq!(report.regressions(&comparison), [&report.measurements[1]]);
+
+        let markdown = report.to_markdown(Some(&comparison));
+        assert!(markdown.contains(
+            "| 2024 | 6 | a | 150.00ns | 150.00ns | 150.00ns | 100.00ns | **+50.0%** regression |"
+        ));
+        assert!(markdown.contains("| 2024 | 6 | b | 100.00ns | 100.00ns | 100.00ns | - | - |"));
+        assert!(markdown.ends_with("1 regression(s) over 10%.\n"));
+
+        assert_eq!(
+            Report::from_json(&report.to_json().unwrap()).unwrap(),
+            report
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::{
+    cell::Cell,
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Requests made by one client are spaced at least this far apart.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Settings read from the config file. Every field can be overridden by an
+/// environment variable.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    /// `AOC_SESSION`: the value of the `session` cookie of a logged in browser.
+    session: Option<String>,
+    /// `AOC_BASE_URL`
+    base_url: Option<String>,
+    /// `AOC_CONTACT`: added to the User-Agent so the site can reach out.
+    contact: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: String,
+    pub base_url: String,
+    pub contact: Option<String>,
+    pub min_interval: Duration,
+}
+
+/// `$AOC_CONFIG`, or `aoc/config.toml` in the user's config directory.
+fn config_path(env: &impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    if let Some(path) = env("AOC_CONFIG") {
+        return Some(path.into());
+    }
+    env("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("aoc/config.toml"))
+}
+
+impl Config {
+    /// Read the config from the environment and the config file.
+    pub fn load() -> Result<Self> {
+        let env = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let file = match config_path(&env) {
+            Some(path) if path.exists() => Some(
+                std::fs::read_to_string(&path)
+                    .with_context(|| format!("failed to read {}", path.display()))?,
+            ),
+            _ => None,
+        };
+        Self::from_sources(env, file.as_deref())
+    }
+
+    fn from_sources(env: impl Fn(&str) -> Option<String>, file: Option<&str>) -> Result<Self> {
+        let file: ConfigFile = match file {
+            Some(s) => toml::from_str(s).context("invalid config file")?,
+            None => ConfigFile::default(),
+        };
+        let session = env("AOC_SESSION").or(file.session).ok_or(anyhow!(
+            "no session token: set AOC_SESSION or `session` in the config file"
+        ))?;
+        // Accept the cookie as copied from the browser, with or without its name.
+        let session = session.trim();
+        let session = session.strip_prefix("session=").unwrap_or(session);
+
+        Ok(Self {
+            session: session.to_owned(),
+            base_url: env("AOC_BASE_URL")
+                .or(file.base_url)
+                .unwrap_or(DEFAULT_BASE_URL.to_owned())
+                .trim_end_matches('/')
+                .to_owned(),
+            contact: env("AOC_CONTACT").or(file.contact),
+            min_interval: DEFAULT_MIN_INTERVAL,
+        })
+    }
+
+    pub fn user_agent(&self) -> String {
+        let agent = concat!("aoc/", env!("CARGO_PKG_VERSION"));
+        match &self.contact {
+            Some(contact) => format!("{agent} (personal puzzle runner; {contact})"),
+            None => format!("{agent} (personal puzzle runner)"),
+        }
+    }
+}
+
+/// A failed request, with the page the server sent back.
+#[derive(Debug)]
+pub struct StatusError {
+    pub status: u16,
+    pub body: String,
+}
+
+impl std::fmt::Display for StatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "server responded with status {}", self.status)
+    }
+}
+
+impl std::error::Error for StatusError {}
+
+/// An authenticated, rate-limited connection to the puzzle site.
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+    last_request: Cell<Option<Instant>>,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&config.user_agent())
+            .timeout(Duration::from_secs(30))
+            .build();
+        Self {
+            config,
+            agent,
+            last_request: Cell::new(None),
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Sleep until the next request is allowed.
+    fn wait_turn(&self) {
+        if let Some(last) = self.last_request.get() {
+            if let Some(wait) = self.config.min_interval.checked_sub(last.elapsed()) {
+                thread::sleep(wait);
+            }
+        }
+        self.last_request.set(Some(Instant::now()));
+    }
+
+    /// Start a request once it is allowed.
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.wait_turn();
+        self.agent
+            .request(method, &format!("{}{path}", self.config.base_url))
+            .set("Cookie", &format!("session={}", self.config.session))
+    }
+
+    fn read(response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, response)) => Err(StatusError {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            }
+            .into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Result<String> {
+        Self::read(self.request("GET", path).call())
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        Self::read(self.request("POST", path).send_form(form))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_config() {
+        assert!(Config::from_sources(env(&[]), None).is_err());
+
+        let file = "session = \"from-file\"\ncontact = \"me@example.com\"\n";
+        let config = Config::from_sources(env(&[]), Some(file)).unwrap();
+        assert_eq!(config.session, "from-file");
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert!(config.user_agent().contains("me@example.com"));
+
+        let vars = [
+            ("AOC_SESSION", "session=from-env\n"),
+            ("AOC_BASE_URL", "http://localhost:1234/"),
+        ];
+        let config = Config::from_sources(env(&vars), Some(file)).unwrap();
+        assert_eq!(config.session, "from-env");
+        assert_eq!(config.base_url, "http://localhost:1234");
+
+        assert!(Config::from_sources(env(&[]), Some("token = \"typo\"")).is_err());
+    }
+
+    #[test]
+    fn test_config_path() {
+        assert_eq!(
+            config_path(&env(&[("HOME", "/home/me")])),
+            Some("/home/me/.config/aoc/config.toml".into())
+        );
+        assert_eq!(
+            config_path(&env(&[
+                ("HOME", "/home/me"),
+                ("AOC_CONFIG", "/etc/aoc.toml")
+            ])),
+            Some("/etc/aoc.toml".into())
+        );
+    }
+}
@@ -0,0 +1,89 @@
+//! A stand-in for the puzzle site, serving canned pages to the client tests.
+
+use std::{
+    sync::{Arc, Mutex},
+    thread::{self, JoinHandle},
+};
+use tiny_http::{Header, Response, Server};
+
+/// A request as the server received it.
+#[derive(Debug, Clone)]
+pub struct Recorded {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub user_agent: Option<String>,
+    pub body: String,
+}
+
+pub struct FakeServer {
+    pub url: String,
+    server: Arc<Server>,
+    requests: Arc<Mutex<Vec<Recorded>>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl FakeServer {
+    /// Serve every request with the status and body `respond` returns for it.
+    pub fn start(respond: impl Fn(&Recorded) -> (u16, String) + Send + 'static) -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let thread = {
+            let server = Arc::clone(&server);
+            let requests = Arc::clone(&requests);
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let header = |name: &str| {
+                        request
+                            .headers()
+                            .iter()
+                            .find(|h| h.field.as_str().as_str().eq_ignore_ascii_case(name))
+                            .map(|h| h.value.to_string())
+                    };
+                    let mut recorded = Recorded {
+                        method: request.method().to_string(),
+                        path: request.url().to_owned(),
+                        cookie: header("Cookie"),
+                        user_agent: header("User-Agent"),
+                        body: String::new(),
+                    };
+                    request
+                        .as_reader()
+                        .read_to_string(&mut recorded.body)
+                        .unwrap();
+
+                    let (status, body) = respond(&recorded);
+                    requests.lock().unwrap().push(recorded);
+                    let content_type =
+                        Header::from_bytes("Content-Type", "text/html; charset=utf-8").unwrap();
+                    let response = Response::from_string(body)
+                        .with_status_code(status)
+                        .with_header(content_type);
+                    request.respond(response).unwrap();
+                }
+            })
+        };
+
+        Self {
+            url,
+            server,
+            requests,
+            thread: Some(thread),
+        }
+    }
+
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
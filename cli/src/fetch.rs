@@ -0,0 +1,161 @@
+use crate::client::{Client, StatusError};
+use anyhow::{anyhow, Context, Result};
+use std::path::{Path, PathBuf};
+
+/// Where a day's input was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub fn input_file(inputs_dir: &Path, day: u32) -> PathBuf {
+    inputs_dir.join(format!("{day}-input.txt"))
+}
+
+/// The day's input if it is already on disk. The empty placeholder left by
+/// `aoc new` does not count.
+pub fn cached_input(inputs_dir: &Path, day: u32) -> Option<PathBuf> {
+    let path = input_file(inputs_dir, day);
+    let len = std::fs::metadata(&path).ok()?.len();
+    (len > 0).then_some(path)
+}
+
+/// Explain a failed input download in terms of what the site meant by it.
+fn describe_failure(error: anyhow::Error, year: u32, day: u32) -> anyhow::Error {
+    let Some(status) = error.downcast_ref::<StatusError>() else {
+        return error;
+    };
+    match status.status {
+        404 => anyhow!("{year} day {day} is not unlocked yet"),
+        400 | 500 if status.body.contains("log in") => {
+            anyhow!("the session token was rejected; it may have expired")
+        }
+        _ => error.context(format!("failed to download {year} day {day}")),
+    }
+}
+
+/// Download the day's input into `inputs_dir`, unless it is already there.
+pub fn fetch_input(client: &Client, year: u32, inputs_dir: &Path, day: u32) -> Result<Fetched> {
+    if let Some(path) = cached_input(inputs_dir, day) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client
+        .get(&format!("/{year}/day/{day}/input"))
+        .map_err(|e| describe_failure(e, year, day))?;
+    // Inputs are never downloaded twice, so an error page must not be cached.
+    if input.is_empty() || input.trim_start().starts_with('<') {
+        return Err(anyhow!(
+            "expected the input of {year} day {day} but got a web page; is the session token valid?"
+        ));
+    }
+
+    let path = input_file(inputs_dir, day);
+    std::fs::create_dir_all(inputs_dir)?;
+    std::fs::write(&path, input).with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::Config, fake_server::FakeServer};
+    use std::time::{Duration, Instant};
+
+    fn client(server: &FakeServer, min_interval: Duration) -> Client {
+        Client::new(Config {
+            session: "secret".to_owned(),
+            base_url: server.url.clone(),
+            contact: Some("me@example.com".to_owned()),
+            min_interval,
+        })
+    }
+
+    fn server() -> FakeServer {
+        FakeServer::start(|request| match request.path.as_str() {
+            "/2024/day/1/input" | "/2024/day/2/input" => (200, "1 2\n3 4\n".to_owned()),
+            "/2024/day/3/input" => (500, "Please log in to get your puzzle input.".to_owned()),
+            "/2024/day/4/input" => (200, "<!DOCTYPE html>\n<html></html>".to_owned()),
+            _ => (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!".to_owned(),
+            ),
+        })
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let server = server();
+        let client = client(&server, Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+        let path = input_file(dir.path(), 1);
+
+        assert_eq!(
+            fetch_input(&client, 2024, dir.path(), 1).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+        assert_eq!(
+            fetch_input(&client, 2024, dir.path(), 1).unwrap(),
+            Fetched::Cached(path)
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+        assert!(requests[0]
+            .user_agent
+            .as_ref()
+            .is_some_and(|agent| agent.contains("me@example.com")));
+    }
+
+    #[test]
+    fn test_placeholder_is_replaced() {
+        let server = server();
+        let client = client(&server, Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(input_file(dir.path(), 2), "").unwrap();
+
+        assert!(matches!(
+            fetch_input(&client, 2024, dir.path(), 2).unwrap(),
+            Fetched::Downloaded(_)
+        ));
+    }
+
+    #[test]
+    fn test_failures_are_not_cached() {
+        let server = server();
+        let client = client(&server, Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+
+        let error = fetch_input(&client, 2024, dir.path(), 3).unwrap_err();
+        assert!(error.to_string().contains("session token"));
+        let error = fetch_input(&client, 2024, dir.path(), 4).unwrap_err();
+        assert!(error.to_string().contains("web page"));
+        let error = fetch_input(&client, 2024, dir.path(), 25).unwrap_err();
+        assert!(error.to_string().contains("not unlocked"));
+
+        for day in [3, 4, 25] {
+            assert!(!input_file(dir.path(), day).exists());
+        }
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let server = server();
+        let interval = Duration::from_millis(300);
+        let client = client(&server, interval);
+        let dir = tempfile::tempdir().unwrap();
+
+        let start = Instant::now();
+        fetch_input(&client, 2024, dir.path(), 1).unwrap();
+        fetch_input(&client, 2024, dir.path(), 2).unwrap();
+        assert!(start.elapsed() >= interval);
+        // Cached inputs don't make requests, so they don't wait either.
+        let start = Instant::now();
+        fetch_input(&client, 2024, dir.path(), 1).unwrap();
+        assert!(start.elapsed() < interval);
+    }
+}
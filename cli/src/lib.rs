@@ -0,0 +1,159 @@
+#![warn(clippy::pedantic)]
+#![allow(clippy::missing_errors_doc, clippy::must_use_candidate)]
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+#[cfg(test)]
+mod fake_server;
+pub mod fetch;
+pub mod scaffold;
+pub mod submit;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::{
+    dot::Dot,
+    solution::{Answer, Part, Year},
+};
+use std::{
+    any::Any,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Every year with registered solutions.
+pub const YEARS: &[&Year] = &[
+    &aoc_2022::YEAR,
+    &aoc_2023::YEAR,
+    &aoc_2024::YEAR,
+    &aoc_2025::YEAR,
+];
+
+pub fn find_year(year: u32) -> Result<&'static Year> {
+    YEARS
+        .iter()
+        .copied()
+        .find(|y| y.year == year)
+        .ok_or_else(|| anyhow!("no solutions registered for year {year}"))
+}
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's real input in the year's inputs directory.
+    Default,
+    /// The day's N-th example in the year's inputs directory, counting from 1.
+    Example(u32),
+    Path(PathBuf),
+    Stdin,
+}
+
+/// File names that may hold the N-th example of a day, most specific first.
+///
+/// Most days have a single `N-example.txt`, but some have numbered examples
+/// (`16-example-2.txt`) or a different example per part (`3a-example.txt`).
+pub fn example_file_names(day: u32, part: Part, n: u32) -> Vec<String> {
+    let mut result = vec![
+        format!("{day}{part}-example-{n}.txt"),
+        format!("{day}-example-{n}.txt"),
+    ];
+    if n == 1 {
+        result.push(format!("{day}{part}-example.txt"));
+        result.push(format!("{day}-example.txt"));
+    }
+    result
+}
+
+/// The file to read an input from, or `None` for standard input.
+pub fn input_path(
+    year: &Year,
+    day: u32,
+    part: Part,
+    source: &InputSource,
+) -> Result<Option<PathBuf>> {
+    let dir = Path::new(year.inputs_dir);
+    match source {
+        InputSource::Default => Ok(Some(dir.join(format!("{day}-input.txt")))),
+        InputSource::Example(n) => example_file_names(day, part, *n)
+            .into_iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.exists())
+            .map(Some)
+            .ok_or_else(|| {
+                anyhow!(
+                    "no example {n} for {} day {day} part {part} in {}",
+                    year.year,
+                    dir.display()
+                )
+            }),
+        InputSource::Path(path) => Ok(Some(path.clone())),
+        InputSource::Stdin => Ok(None),
+    }
+}
+
+/// Read an input from a file, or from standard input if there is no path.
+pub fn read_input(path: Option<&Path>) -> Result<String> {
+    if let Some(path) = path {
+        return std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()));
+    }
+
+    let mut result = String::new();
+    std::io::stdin()
+        .read_to_string(&mut result)
+        .context("failed to read standard input")?;
+    Ok(result)
+}
+
+/// Run the given parts of a day and return their answers. Parts that read the
+/// same input share one parse of it.
+pub fn run(year: u32, day: u32, parts: &[Part], source: &InputSource) -> Result<Vec<Answer>> {
+    let year = find_year(year)?;
+    let solution = year
+        .day(day)
+        .ok_or_else(|| anyhow!("no solution for {} day {day}", year.year))?;
+
+    let mut parsed: Option<(Option<PathBuf>, Box<dyn Any>)> = None;
+    let mut result = Vec::new();
+    for &part in parts {
+        let path = input_path(year, day, part, source)?;
+        let input = match parsed {
+            Some((parsed_path, input)) if parsed_path == path => input,
+            _ => solution.parse_any(&read_input(path.as_deref())?)?,
+        };
+        result.push(solution.solve_any(input.as_ref(), part)?);
+        parsed = Some((path, input));
+    }
+    Ok(result)
+}
+
+/// Draw the input of a day, for the days whose input is a graph.
+pub fn draw(year: u32, day: u32, source: &InputSource) -> Result<Dot> {
+    let year = find_year(year)?;
+    let solution = year
+        .day(day)
+        .ok_or_else(|| anyhow!("no solution for {} day {day}", year.year))?;
+    let path = input_path(year, day, Part::A, source)?;
+    let input = solution.parse_any(&read_input(path.as_deref())?)?;
+    solution.dot_any(input.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_paths() {
+        let year = find_year(2024).unwrap();
+        let path = |day, part, n| {
+            input_path(year, day, part, &InputSource::Example(n))
+                .ok()
+                .flatten()
+                .and_then(|p| Some(p.file_name()?.to_str()?.to_owned()))
+        };
+        assert_eq!(path(1, Part::A, 1).as_deref(), Some("1-example.txt"));
+        assert_eq!(path(3, Part::B, 1).as_deref(), Some("3b-example.txt"));
+        assert_eq!(path(16, Part::A, 2).as_deref(), Some("16-example-2.txt"));
+        assert_eq!(path(1, Part::A, 2), None);
+    }
+}
@@ -0,0 +1,262 @@
+#![warn(clippy::pedantic)]
+
+use anyhow::{Context, Result};
+use aoc::{
+    bench::{bench_day, select_parts, Comparison, Report},
+    client::{Client, Config},
+    fetch::{fetch_input, Fetched},
+    scaffold::{new_day, WORKSPACE_DIR},
+    submit::{log_path, submit, Outcome},
+    InputSource,
+};
+use aoc_common::solution::Part;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::{Path, PathBuf};
+
+/// Run Advent of Code solutions.
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve a day and print the answers.
+    Run(RunArgs),
+    /// Time parsing and solving on the real inputs. Build with --release for
+    /// meaningful numbers.
+    Bench(BenchArgs),
+    /// Create the skeleton and input placeholders of a new day and register
+    /// it. Never overwrites an existing day.
+    New(NewArgs),
+    /// Download puzzle inputs that are not in the year's inputs directory
+    /// yet. Needs a session token in `AOC_SESSION` or `~/.config/aoc/config.toml`.
+    Fetch(FetchArgs),
+    /// Submit an answer, unless the year's submission log already tells how
+    /// it fares.
+    Submit(SubmitArgs),
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    #[arg(long)]
+    year: u32,
+    #[arg(long)]
+    day: u32,
+    /// Which part to solve: a or b. Solves both parts if omitted.
+    #[arg(long)]
+    part: Option<Part>,
+    /// Read the input from a file instead of the day's input.
+    #[arg(long, value_name = "PATH", group = "source")]
+    input: Option<PathBuf>,
+    /// Use the day's N-th example as input.
+    #[arg(long, value_name = "N", group = "source")]
+    example: Option<u32>,
+    /// Pass `-` to read the input from standard input.
+    #[arg(value_parser = ["-"], group = "source")]
+    stdin: Option<String>,
+    /// Write a Graphviz drawing of the input to a file instead of solving,
+    /// for the days whose input is a graph.
+    #[arg(long, value_name = "PATH", conflicts_with = "part")]
+    emit_dot: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+struct NewArgs {
+    year: u32,
+    day: u32,
+}
+
+#[derive(Debug, Args)]
+struct FetchArgs {
+    #[arg(long)]
+    year: u32,
+    /// Fetch only this day. Otherwise every registered day is fetched.
+    #[arg(long)]
+    day: Option<u32>,
+}
+
+#[derive(Debug, Args)]
+struct SubmitArgs {
+    #[arg(long)]
+    year: u32,
+    #[arg(long)]
+    day: u32,
+    #[arg(long)]
+    part: Part,
+    /// The answer to submit. Solves the part on the day's input if omitted.
+    answer: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Markdown,
+    Json,
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    #[arg(long)]
+    year: u32,
+    /// Benchmark only this day. Otherwise every part with a verified answer
+    /// is benchmarked.
+    #[arg(long)]
+    day: Option<u32>,
+    #[arg(long)]
+    part: Option<Part>,
+    /// How many times to run each stage.
+    #[arg(long, short = 'n', default_value_t = 10)]
+    iterations: usize,
+    #[arg(long, value_enum, default_value_t = Format::Markdown)]
+    format: Format,
+    /// Write the report to a file instead of standard output.
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+    /// A JSON report from an earlier run to compare against.
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+    /// Slowdown of the median, in percent, that counts as a regression.
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+impl RunArgs {
+    fn source(&self) -> InputSource {
+        if self.stdin.is_some() || self.input.as_deref() == Some("-".as_ref()) {
+            InputSource::Stdin
+        } else if let Some(path) = &self.input {
+            InputSource::Path(path.clone())
+        } else if let Some(n) = self.example {
+            InputSource::Example(n)
+        } else {
+            InputSource::Default
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run(args) => run(&args),
+        Command::Bench(args) => bench(&args),
+        Command::New(args) => new(&args),
+        Command::Fetch(args) => fetch(&args),
+        Command::Submit(args) => submit_answer(&args),
+    }
+}
+
+fn run(args: &RunArgs) -> Result<()> {
+    if let Some(path) = &args.emit_dot {
+        let dot = aoc::draw(args.year, args.day, &args.source())?;
+        std::fs::write(path, dot.to_string())
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        println!("wrote {}", path.display());
+        return Ok(());
+    }
+
+    if let Some(part) = args.part {
+        let answers = aoc::run(args.year, args.day, &[part], &args.source())?;
+        println!("{}", answers[0]);
+    } else {
+        let answers = aoc::run(args.year, args.day, &Part::ALL, &args.source())?;
+        for (part, answer) in Part::ALL.iter().zip(answers) {
+            println!("{part}: {answer}");
+        }
+    }
+
+    Ok(())
+}
+
+fn read_report(path: &Path) -> Result<Report> {
+    let s = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    Report::from_json(&s).with_context(|| format!("invalid report {}", path.display()))
+}
+
+fn bench(args: &BenchArgs) -> Result<()> {
+    let year = aoc::find_year(args.year)?;
+    let baseline = args.baseline.as_deref().map(read_report).transpose()?;
+
+    let mut report = Report::default();
+    for (day, parts) in select_parts(year, args.day, args.part)? {
+        eprintln!("benchmarking {} day {day}", year.year);
+        report
+            .measurements
+            .extend(bench_day(year, day, &parts, args.iterations)?);
+    }
+
+    let comparison = baseline.as_ref().map(|baseline| Comparison {
+        baseline,
+        threshold: args.threshold,
+    });
+    let output = match args.format {
+        Format::Markdown => report.to_markdown(comparison.as_ref()),
+        Format::Json => report.to_json()? + "\n",
+    };
+    if let Some(path) = &args.output {
+        std::fs::write(path, output)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    } else {
+        print!("{output}");
+    }
+
+    if let Some(comparison) = &comparison {
+        let count = report.regressions(comparison).len();
+        if count > 0 {
+            eprintln!("{count} regression(s) over {}%", args.threshold);
+        }
+    }
+    Ok(())
+}
+
+fn new(args: &NewArgs) -> Result<()> {
+    let root = Path::new(WORKSPACE_DIR);
+    for path in new_day(root, args.year, args.day)? {
+        println!("wrote {}", path.strip_prefix(root)?.display());
+    }
+    Ok(())
+}
+
+fn fetch(args: &FetchArgs) -> Result<()> {
+    let year = aoc::find_year(args.year)?;
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => year.days.iter().map(|&(day, _)| day).collect(),
+    };
+
+    let client = Client::new(Config::load()?);
+    for day in days {
+        match fetch_input(&client, year.year, Path::new(year.inputs_dir), day)? {
+            Fetched::Cached(path) => println!("day {day}: already have {}", path.display()),
+            Fetched::Downloaded(path) => println!("day {day}: saved {}", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn submit_answer(args: &SubmitArgs) -> Result<()> {
+    let year = aoc::find_year(args.year)?;
+    let answer = if let Some(answer) = &args.answer {
+        answer.clone()
+    } else {
+        let answers = aoc::run(year.year, args.day, &[args.part], &InputSource::Default)?;
+        answers[0].to_string()
+    };
+
+    let client = Client::new(Config::load()?);
+    let log = log_path(year);
+    match submit(&client, &log, year.year, args.day, args.part, &answer)? {
+        Outcome::Checked(verdict) => println!("{answer}: {verdict}"),
+        Outcome::Known(verdict) => {
+            println!(
+                "{answer}: {verdict} (known from {}, not submitted)",
+                log.display()
+            );
+        }
+        Outcome::RateLimited(Some(wait)) => {
+            println!("{answer}: not checked, try again in {}s", wait.as_secs());
+        }
+        Outcome::RateLimited(None) => println!("{answer}: not checked, submitted too recently"),
+    }
+    Ok(())
+}
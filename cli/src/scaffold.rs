@@ -0,0 +1,464 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use toml_edit::{value, DocumentMut, InlineTable, Item, Key, Table, Value};
+
+/// The root of the workspace this binary was built from.
+pub const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const MANIFEST_HEADER: &str = "\
+# Verified answers, checked by `cargo test` in the cli crate. `examples` maps
+# the number passed to `aoc run --example` to the expected answer.
+";
+
+fn day_template(day: u32) -> String {
+    format!(
+        "\
+use anyhow::{{anyhow, Result}};
+use aoc_common::solution::{{Answer, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        Ok(input.lines().map(str::to_owned).collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> Result<Answer> {{
+        Err(anyhow!(\"part 1 is not solved\"))
+    }}
+}}
+"
+    )
+}
+
+fn year_manifest(year: u32) -> String {
+    format!(
+        "\
+[package]
+name = \"aoc-{year}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[dependencies]
+aoc-common.workspace = true
+anyhow.workspace = true
+itertools.workspace = true
+regex.workspace = true
+"
+    )
+}
+
+fn year_lib(year: u32, days: &[u32]) -> String {
+    let mut days = days.to_vec();
+    days.sort_unstable();
+    let entries: Vec<_> = days
+        .iter()
+        .map(|d| format!("({d}, &day{d}::Day{d})"))
+        .collect();
+
+    format!(
+        "\
+#![warn(clippy::pedantic)]
+#![allow(clippy::missing_errors_doc)]
+
+use aoc_common::solution::Year;
+
+{}
+pub const YEAR: Year = Year {{
+    year: {year},
+    inputs_dir: concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/inputs\"),
+    days: {},
+}};
+",
+        module_lines(&days),
+        format_array(&entries, 4)
+    )
+}
+
+/// The `mod` lines of some days, sorted by name as rustfmt does.
+fn module_lines(days: &[u32]) -> String {
+    let mut names: Vec<_> = days.iter().map(|d| format!("day{d}")).collect();
+    names.sort();
+    let lines: Vec<_> = names.iter().map(|name| format!("mod {name};\n")).collect();
+    lines.concat()
+}
+
+/// Lay out an array literal the way rustfmt does: on one line while it is
+/// short, otherwise one item per line.
+fn format_array(items: &[String], indent: usize) -> String {
+    let single = format!("&[{}]", items.join(", "));
+    if single.len() <= 60 {
+        return single;
+    }
+    let pad = " ".repeat(indent + 4);
+    let lines: Vec<_> = items.iter().map(|i| format!("{pad}{i},\n")).collect();
+    format!("&[\n{}{}]", lines.concat(), " ".repeat(indent))
+}
+
+/// Byte range of the `&[...]` literal that follows `marker` in `s`.
+fn array_after(s: &str, marker: &str) -> Result<(usize, usize)> {
+    let start = s.find(marker).ok_or(anyhow!("missing `{marker}`"))? + marker.len();
+    if !s[start..].starts_with("&[") {
+        bail!("expected an array after `{marker}`");
+    }
+    let end = s[start..]
+        .find(']')
+        .ok_or(anyhow!("unterminated array after `{marker}`"))?;
+    Ok((start, start + end + 1))
+}
+
+/// Days registered in a year crate's lib.rs.
+fn registered_days(lib: &str) -> Vec<u32> {
+    lib.lines()
+        .filter_map(|l| l.strip_prefix("mod day")?.strip_suffix(';')?.parse().ok())
+        .collect()
+}
+
+/// Add `day` to the modules and the `days` list of a year crate's lib.rs.
+fn register_day(lib: &str, day: u32) -> Result<String> {
+    let mut days = registered_days(lib);
+    if days.contains(&day) {
+        bail!("day {day} is already registered");
+    }
+    days.push(day);
+    days.sort_unstable();
+
+    let first_mod = lib.find("mod day").ok_or(anyhow!("no day modules found"))?;
+    let without_mods: String = lib
+        .split_inclusive('\n')
+        .filter(|l| registered_days(l).is_empty())
+        .collect();
+    let mut result = without_mods.clone();
+    result.insert_str(first_mod, &module_lines(&days));
+
+    let entries: Vec<_> = days
+        .iter()
+        .map(|d| format!("({d}, &day{d}::Day{d})"))
+        .collect();
+    let (start, end) = array_after(&result, "days: ")?;
+    result.replace_range(start..end, &format_array(&entries, 4));
+    Ok(result)
+}
+
+/// Add a year crate to the runner's `YEARS`.
+fn register_year(lib: &str, year: u32) -> Result<String> {
+    let (start, end) = array_after(lib, "pub const YEARS: &[&Year] = ")?;
+    let mut years: Vec<u32> = lib[start + 2..end - 1]
+        .split(',')
+        .filter_map(|y| y.trim().strip_prefix("&aoc_")?.strip_suffix("::YEAR"))
+        .map(str::parse)
+        .collect::<Result<_, _>>()?;
+    if years.contains(&year) {
+        bail!("year {year} is already registered");
+    }
+    years.push(year);
+    years.sort_unstable();
+
+    let entries: Vec<_> = years.iter().map(|y| format!("&aoc_{y}::YEAR")).collect();
+    let mut result = lib.to_owned();
+    result.replace_range(start..end, &format_array(&entries, 0));
+    Ok(result)
+}
+
+/// Move the last entry of `table` to just after the last key that
+/// `is_sibling` accepts, so related keys stay together.
+fn move_last_after(table: &mut Table, is_sibling: impl Fn(&str) -> bool) {
+    let mut entries: Vec<(Key, Item)> = table
+        .iter()
+        .map(|(k, _)| {
+            let (key, item) = table.get_key_value(k).unwrap();
+            (key.clone(), item.clone())
+        })
+        .collect();
+    let Some(last) = entries.pop() else {
+        return;
+    };
+    let position = entries
+        .iter()
+        .rposition(|(k, _)| is_sibling(k.get()))
+        .map_or(entries.len(), |i| i + 1);
+    entries.insert(position, last);
+
+    table.clear();
+    for (key, item) in entries {
+        table.insert_formatted(&key, item);
+    }
+}
+
+/// Add a year crate to the root manifest's members and shared dependencies.
+fn add_workspace_member(manifest: &str, year: u32) -> Result<String> {
+    let mut doc: DocumentMut = manifest.parse()?;
+    let workspace = doc["workspace"]
+        .as_table_mut()
+        .ok_or(anyhow!("missing [workspace]"))?;
+
+    let members = workspace["members"]
+        .as_array_mut()
+        .ok_or(anyhow!("missing workspace members"))?;
+    // The shared crates come first, then the years in order.
+    let position = members
+        .iter()
+        .filter(|m| m.as_str().and_then(|m| m.parse::<u32>().ok()) < Some(year))
+        .count();
+    members.insert(position, year.to_string());
+
+    let dependencies = workspace["dependencies"]
+        .as_table_mut()
+        .ok_or(anyhow!("missing [workspace.dependencies]"))?;
+    let mut path = InlineTable::new();
+    path.insert("path", year.to_string().into());
+    dependencies.insert(&format!("aoc-{year}"), value(path));
+    move_last_after(dependencies, |k| k.starts_with("aoc-"));
+
+    Ok(doc.to_string())
+}
+
+/// Add a year crate to the runner's dependencies.
+fn add_cli_dependency(manifest: &str, year: u32) -> Result<String> {
+    let mut doc: DocumentMut = manifest.parse()?;
+    let dependencies = doc["dependencies"]
+        .as_table_mut()
+        .ok_or(anyhow!("missing [dependencies]"))?;
+    let mut workspace = InlineTable::new();
+    workspace.insert("workspace", true.into());
+    workspace.set_dotted(true);
+    dependencies.insert(
+        &format!("aoc-{year}"),
+        Item::Value(Value::InlineTable(workspace)),
+    );
+    move_last_after(dependencies, |k| k.starts_with("aoc-"));
+    Ok(doc.to_string())
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+/// Files to write, checked in full before anything touches the disk.
+#[derive(Debug, Default)]
+struct Changes(Vec<(PathBuf, String)>);
+
+impl Changes {
+    fn add(&mut self, path: PathBuf, contents: String) {
+        self.0.push((path, contents));
+    }
+
+    fn write(self) -> Result<Vec<PathBuf>> {
+        let mut written = Vec::new();
+        for (path, contents) in self.0 {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, contents)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+/// Create the solution skeleton and input placeholders of a day and register
+/// it, creating the year's crate first if needed. Returns the files written.
+///
+/// Refuses to touch a day that already has a module, and never overwrites
+/// existing inputs.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>> {
+    if year < 2015 {
+        bail!("there is no Advent of Code {year}");
+    }
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25, got {day}");
+    }
+
+    let year_dir = root.join(year.to_string());
+    let module = year_dir.join(format!("src/day{day}.rs"));
+    if module.exists() {
+        bail!("day {day} of {year} already exists: {year}/src/day{day}.rs");
+    }
+
+    let mut changes = Changes::default();
+    let lib = year_dir.join("src/lib.rs");
+    if year_dir.join("Cargo.toml").exists() {
+        changes.add(lib.clone(), register_day(&read(&lib)?, day)?);
+    } else {
+        let root_manifest = root.join("Cargo.toml");
+        let cli_manifest = root.join("cli/Cargo.toml");
+        let cli_lib = root.join("cli/src/lib.rs");
+        changes.add(
+            root_manifest.clone(),
+            add_workspace_member(&read(&root_manifest)?, year)?,
+        );
+        changes.add(
+            cli_manifest.clone(),
+            add_cli_dependency(&read(&cli_manifest)?, year)?,
+        );
+        changes.add(cli_lib.clone(), register_year(&read(&cli_lib)?, year)?);
+        changes.add(year_dir.join("Cargo.toml"), year_manifest(year));
+        changes.add(lib, year_lib(year, &[day]));
+        changes.add(
+            year_dir.join("inputs/answers.toml"),
+            MANIFEST_HEADER.to_owned(),
+        );
+    }
+    changes.add(module, day_template(day));
+    for name in [format!("{day}-example.txt"), format!("{day}-input.txt")] {
+        let path = year_dir.join("inputs").join(name);
+        if !path.exists() {
+            changes.add(path, String::new());
+        }
+    }
+
+    changes.write()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_array() {
+        let short = ["(2, &day2::Day2)".to_owned()];
+        assert_eq!(format_array(&short, 4), "&[(2, &day2::Day2)]");
+        let long: Vec<_> = (1..=4).map(|d| format!("({d}, &day{d}::Day{d})")).collect();
+        assert_eq!(
+            format_array(&long, 4),
+            "&[\n        (1, &day1::Day1),\n        (2, &day2::Day2),\n        \
+             (3, &day3::Day3),\n        (4, &day4::Day4),\n    ]"
+        );
+    }
+
+    #[test]
+    fn test_register_day() {
+        let lib = year_lib(2022, &[2, 15]);
+        assert_eq!(registered_days(&lib), [15, 2]);
+        assert_eq!(register_day(&lib, 9).unwrap(), year_lib(2022, &[2, 9, 15]));
+        assert!(register_day(&lib, 15).is_err());
+
+        // The generated layout is the one the year crates already use.
+        let existing = "\
+#![warn(clippy::pedantic)]
+#![allow(clippy::missing_errors_doc)]
+
+use aoc_common::solution::Year;
+
+mod day1;
+mod day10;
+mod day2;
+mod day3;
+
+pub const YEAR: Year = Year {
+    year: 2025,
+    inputs_dir: concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/inputs\"),
+    days: &[
+        (1, &day1::Day1),
+        (2, &day2::Day2),
+        (3, &day3::Day3),
+        (10, &day10::Day10),
+    ],
+};
+";
+        assert_eq!(registered_days(existing), [1, 10, 2, 3]);
+        assert_eq!(year_lib(2025, &[1, 2, 3, 10]), existing);
+    }
+
+    #[test]
+    fn test_register_year() {
+        let lib = "pub const YEARS: &[&Year] = &[&aoc_2022::YEAR, &aoc_2024::YEAR];\n";
+        assert_eq!(
+            register_year(lib, 2023).unwrap(),
+            "pub const YEARS: &[&Year] = &[&aoc_2022::YEAR, &aoc_2023::YEAR, &aoc_2024::YEAR];\n"
+        );
+        assert!(register_year(lib, 2024).is_err());
+    }
+
+    #[test]
+    fn test_manifests() {
+        let root = "\
+[workspace]
+members = [\"common\", \"cli\", \"2022\", \"2024\"]
+
+[workspace.dependencies]
+aoc-common = { path = \"common\" }
+aoc-2022 = { path = \"2022\" }
+aoc-2024 = { path = \"2024\" }
+anyhow = \"1.0\"
+";
+        assert_eq!(
+            add_workspace_member(root, 2025).unwrap(),
+            "\
+[workspace]
+members = [\"common\", \"cli\", \"2022\", \"2024\", \"2025\"]
+
+[workspace.dependencies]
+aoc-common = { path = \"common\" }
+aoc-2022 = { path = \"2022\" }
+aoc-2024 = { path = \"2024\" }
+aoc-2025 = { path = \"2025\" }
+anyhow = \"1.0\"
+"
+        );
+
+        let cli = "\
+[dependencies]
+anyhow.workspace = true
+aoc-2024.workspace = true
+clap.workspace = true
+";
+        assert_eq!(
+            add_cli_dependency(cli, 2025).unwrap(),
+            "\
+[dependencies]
+anyhow.workspace = true
+aoc-2024.workspace = true
+aoc-2025.workspace = true
+clap.workspace = true
+"
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        fs::create_dir_all(root.join("cli/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"cli\"]\n\n[workspace.dependencies]\n",
+        )
+        .unwrap();
+        fs::write(root.join("cli/Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(
+            root.join("cli/src/lib.rs"),
+            "pub const YEARS: &[&Year] = &[];\n",
+        )
+        .unwrap();
+
+        new_day(root, 2026, 3).unwrap();
+        assert!(root.join("2026/src/day3.rs").exists());
+        assert!(root.join("2026/inputs/3-input.txt").exists());
+        assert!(read(&root.join("cli/src/lib.rs"))
+            .unwrap()
+            .contains("&aoc_2026::YEAR"));
+
+        fs::write(root.join("2026/inputs/1-input.txt"), "downloaded").unwrap();
+        new_day(root, 2026, 1).unwrap();
+        assert_eq!(
+            registered_days(&read(&root.join("2026/src/lib.rs")).unwrap()),
+            [1, 3]
+        );
+        assert_eq!(
+            read(&root.join("2026/inputs/1-input.txt")).unwrap(),
+            "downloaded"
+        );
+
+        fs::write(root.join("2026/src/day3.rs"), "edited").unwrap();
+        assert!(new_day(root, 2026, 3).is_err());
+        assert_eq!(read(&root.join("2026/src/day3.rs")).unwrap(), "edited");
+        assert!(new_day(root, 2026, 26).is_err());
+    }
+}
@@ -0,0 +1,337 @@
+use crate::client::Client;
+use anyhow::{anyhow, Context, Result};
+use aoc_common::solution::{Part, Year};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    cmp::Ordering,
+    fmt,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// The site's reply to a submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reply {
+    Verdict(Verdict),
+    /// The answer was not checked. Holds the wait the site asked for, if it
+    /// said how long.
+    RateLimited(Option<Duration>),
+}
+
+/// Parse a wait like `1m 5s` from "You have 1m 5s left to wait".
+fn parse_wait(page: &str) -> Option<Duration> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ")?;
+    let mut result = 0;
+    for part in wait.split_whitespace() {
+        let unit = match part.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        result += part[..part.len() - 1].parse::<u64>().ok()? * unit;
+    }
+    Some(Duration::from_secs(result))
+}
+
+pub fn parse_reply(page: &str) -> Result<Reply> {
+    if page.contains("You gave an answer too recently") {
+        Ok(Reply::RateLimited(parse_wait(page)))
+    } else if page.contains("That's the right answer") {
+        Ok(Reply::Verdict(Verdict::Correct))
+    } else if page.contains("That's not the right answer") {
+        Ok(Reply::Verdict(
+            if page.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            },
+        ))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Err(anyhow!(
+            "the site did not accept an answer for this part; is it already solved or still locked?"
+        ))
+    } else {
+        Err(anyhow!("unrecognised reply from the site"))
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn serialize_part<S: Serializer>(part: &Part, s: S) -> Result<S::Ok, S::Error> {
+    s.collect_str(part)
+}
+
+fn deserialize_part<'de, D: Deserializer<'de>>(d: D) -> Result<Part, D::Error> {
+    String::deserialize(d)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+/// One answer the site has checked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Submission {
+    pub day: u32,
+    #[serde(
+        serialize_with = "serialize_part",
+        deserialize_with = "deserialize_part"
+    )]
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted for a year, so none is ever sent twice.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Log {
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+/// The log of a year lives next to its inputs.
+pub fn log_path(year: &Year) -> PathBuf {
+    PathBuf::from(year.inputs_dir).join("submissions.toml")
+}
+
+impl Log {
+    /// Read a log, which is empty if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let s = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&s).with_context(|| format!("invalid submission log {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// What the earlier submissions already tell about `answer`: an exact
+    /// repeat, a different answer to a solved part, or a number beyond one
+    /// that was too high or too low.
+    pub fn known(&self, day: u32, part: Part, answer: &str) -> Option<Verdict> {
+        let earlier = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part);
+        let number = answer.parse::<i128>().ok();
+        let mut result = None;
+        for submission in earlier {
+            if submission.answer == answer {
+                return Some(submission.verdict);
+            }
+            let beyond = |ordering| {
+                let bound = submission.answer.parse::<i128>().ok();
+                matches!((number, bound), (Some(n), Some(b)) if n.cmp(&b) != ordering)
+            };
+            match submission.verdict {
+                Verdict::Correct => result = Some(Verdict::Wrong),
+                Verdict::TooHigh if beyond(Ordering::Less) => {
+                    result = result.or(Some(Verdict::TooHigh));
+                }
+                Verdict::TooLow if beyond(Ordering::Greater) => {
+                    result = result.or(Some(Verdict::TooLow));
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+}
+
+/// The result of `submit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The site checked the answer.
+    Checked(Verdict),
+    /// The log already tells how the answer fares, so it was not sent.
+    Known(Verdict),
+    RateLimited(Option<Duration>),
+}
+
+/// Submit an answer unless the log at `log` already knows its verdict, and
+/// record what the site says about it.
+pub fn submit(
+    client: &Client,
+    log: &Path,
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+) -> Result<Outcome> {
+    let answer = answer.trim();
+    if answer.is_empty() {
+        return Err(anyhow!("refusing to submit an empty answer"));
+    }
+    let mut submissions = Log::load(log)?;
+    if let Some(verdict) = submissions.known(day, part, answer) {
+        return Ok(Outcome::Known(verdict));
+    }
+
+    let level = match part {
+        Part::A => "1",
+        Part::B => "2",
+    };
+    let page = client.post_form(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", level), ("answer", answer)],
+    )?;
+    let verdict = match parse_reply(&page)? {
+        Reply::Verdict(verdict) => verdict,
+        Reply::RateLimited(wait) => return Ok(Outcome::RateLimited(wait)),
+    };
+
+    submissions.submissions.push(Submission {
+        day,
+        part,
+        answer: answer.to_owned(),
+        verdict,
+    });
+    submissions.save(log)?;
+    Ok(Outcome::Checked(verdict))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::Config, fake_server::FakeServer};
+
+    fn page(message: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html><main>\n<article><p>{message}</p></article>\n</main></html>"
+        )
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Reply::Verdict(Verdict::Correct),
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+                Reply::Verdict(Verdict::Wrong),
+            ),
+            (
+                "That's not the right answer; your answer is too high.  Please wait one minute.",
+                Reply::Verdict(Verdict::TooHigh),
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Reply::Verdict(Verdict::TooLow),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+                Reply::RateLimited(Some(Duration::from_secs(65))),
+            ),
+        ];
+        for (message, expected) in cases {
+            assert_eq!(parse_reply(&page(message)).unwrap(), expected, "{message}");
+        }
+        assert!(parse_reply(&page("You don't seem to be solving the right level.")).is_err());
+        assert!(parse_reply("").is_err());
+    }
+
+    #[test]
+    fn test_known() {
+        let submission = |answer: &str, verdict| Submission {
+            day: 1,
+            part: Part::A,
+            answer: answer.to_owned(),
+            verdict,
+        };
+        let log = Log {
+            submissions: vec![
+                submission("abc", Verdict::Wrong),
+                submission("100", Verdict::TooHigh),
+                submission("10", Verdict::TooLow),
+            ],
+        };
+        assert_eq!(log.known(1, Part::A, "abc"), Some(Verdict::Wrong));
+        assert_eq!(log.known(1, Part::A, "150"), Some(Verdict::TooHigh));
+        assert_eq!(log.known(1, Part::A, "-3"), Some(Verdict::TooLow));
+        assert_eq!(log.known(1, Part::A, "50"), None);
+        assert_eq!(log.known(1, Part::B, "abc"), None);
+        assert_eq!(log.known(2, Part::A, "150"), None);
+
+        let mut solved = log;
+        solved.submissions.push(submission("42", Verdict::Correct));
+        assert_eq!(solved.known(1, Part::A, "42"), Some(Verdict::Correct));
+        assert_eq!(solved.known(1, Part::A, "43"), Some(Verdict::Wrong));
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = FakeServer::start(|request| {
+            let message = match request.body.as_str() {
+                "level=1&answer=42" => "That's the right answer!",
+                "level=1&answer=7" => "You gave an answer too recently; You have 30s left to wait.",
+                _ => "That's not the right answer; your answer is too high.",
+            };
+            (200, page(message))
+        });
+        let client = Client::new(Config {
+            session: "secret".to_owned(),
+            base_url: server.url.clone(),
+            contact: None,
+            min_interval: Duration::ZERO,
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("submissions.toml");
+        let submit = |answer| submit(&client, &log, 2024, 1, Part::A, answer).unwrap();
+
+        assert_eq!(
+            submit("7"),
+            Outcome::RateLimited(Some(Duration::from_secs(30)))
+        );
+        assert_eq!(submit("100"), Outcome::Checked(Verdict::TooHigh));
+        assert_eq!(submit("100"), Outcome::Known(Verdict::TooHigh));
+        assert_eq!(submit("120"), Outcome::Known(Verdict::TooHigh));
+        assert_eq!(submit("42\n"), Outcome::Checked(Verdict::Correct));
+        assert_eq!(submit("42"), Outcome::Known(Verdict::Correct));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2024/day/1/answer");
+
+        let recorded = Log::load(&log).unwrap();
+        assert_eq!(
+            recorded
+                .submissions
+                .iter()
+                .map(|s| (s.answer.as_str(), s.verdict))
+                .collect::<Vec<_>>(),
+            [("100", Verdict::TooHigh), ("42", Verdict::Correct)]
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use aoc::{
+    answers::{load_checks, verify},
+    InputSource, YEARS,
+};
+
+/// Run every recorded check whose input matches `filter` and report all
+/// mismatches at once.
+fn check_all(filter: impl Fn(&InputSource) -> bool) {
+    let mut failures = Vec::new();
+    let mut count = 0;
+    for year in YEARS {
+        for check in load_checks(year).unwrap() {
+            if filter(&check.source) {
+                count += 1;
+                failures.extend(verify(year, &check));
+            }
+        }
+    }
+    assert!(count > 0, "no answers recorded");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn test_examples() {
+    check_all(|source| matches!(source, InputSource::Example(_)));
+}
+
+#[test]
+fn test_inputs() {
+    check_all(|source| *source == InputSource::Default);
+}
@@ -0,0 +1,203 @@
+/// The integer points `p` with `min[i] <= p[i] < max[i]` on every axis `i`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize> {
+    pub min: [i64; N],
+    pub max: [i64; N],
+}
+
+impl<const N: usize> Cuboid<N> {
+    pub fn new(min: [i64; N], max: [i64; N]) -> Self {
+        Self { min, max }
+    }
+
+    /// The cuboid with corners `min` and `max`, both included.
+    pub fn inclusive(min: [i64; N], max: [i64; N]) -> Self {
+        Self {
+            min,
+            max: max.map(|m| m + 1),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|i| self.min[i] >= self.max[i])
+    }
+
+    /// The number of points inside.
+    pub fn volume(&self) -> u128 {
+        if self.is_empty() {
+            return 0;
+        }
+        (0..N)
+            .map(|i| u128::from(self.min[i].abs_diff(self.max[i])))
+            .product()
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] < self.max[i])
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let result = Self {
+            min: std::array::from_fn(|i| self.min[i].max(other.min[i])),
+            max: std::array::from_fn(|i| self.max[i].min(other.max[i])),
+        };
+        (!result.is_empty()).then_some(result)
+    }
+
+    /// Cut across `axis` into the points below `at` and the points from `at`
+    /// on, either of which may be empty.
+    pub fn split(&self, axis: usize, at: i64) -> (Option<Self>, Option<Self>) {
+        let (mut below, mut above) = (*self, *self);
+        below.max[axis] = at.min(self.max[axis]);
+        above.min[axis] = at.max(self.min[axis]);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+
+    /// The points not in `other`, as at most `2 * N` disjoint cuboids.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(overlap) = self.intersection(other) else {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        };
+        // Peel off the slabs on either side of the overlap one axis at a
+        // time, leaving the overlap itself.
+        let mut result = Vec::new();
+        let mut rest = *self;
+        for axis in 0..N {
+            let (below, _) = rest.split(axis, overlap.min[axis]);
+            let (_, above) = rest.split(axis, overlap.max[axis]);
+            result.extend(below);
+            result.extend(above);
+            rest.min[axis] = overlap.min[axis];
+            rest.max[axis] = overlap.max[axis];
+        }
+        result
+    }
+}
+
+/// A set of points kept as a union of disjoint cuboids, so that its volume is
+/// the sum of theirs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Boxes<const N: usize> {
+    cuboids: Vec<Cuboid<N>>,
+}
+
+impl<const N: usize> Default for Boxes<N> {
+    fn default() -> Self {
+        Self {
+            cuboids: Vec::new(),
+        }
+    }
+}
+
+impl<const N: usize> FromIterator<Cuboid<N>> for Boxes<N> {
+    fn from_iter<I: IntoIterator<Item = Cuboid<N>>>(iter: I) -> Self {
+        let mut result = Self::new();
+        for cuboid in iter {
+            result.insert(cuboid);
+        }
+        result
+    }
+}
+
+impl<const N: usize> Boxes<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The disjoint cuboids that make up the set.
+    pub fn cuboids(&self) -> &[Cuboid<N>] {
+        &self.cuboids
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cuboids.is_empty()
+    }
+
+    pub fn volume(&self) -> u128 {
+        self.cuboids.iter().map(Cuboid::volume).sum()
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.cuboids.iter().any(|c| c.contains(point))
+    }
+
+    /// Add the points of `cuboid` that are not in the set yet.
+    pub fn insert(&mut self, cuboid: Cuboid<N>) {
+        let mut pieces = vec![cuboid];
+        for existing in &self.cuboids {
+            pieces = pieces
+                .iter()
+                .flat_map(|piece| piece.difference(existing))
+                .collect();
+        }
+        self.cuboids.extend(pieces);
+    }
+
+    pub fn remove(&mut self, cuboid: &Cuboid<N>) {
+        self.cuboids = self
+            .cuboids
+            .iter()
+            .flat_map(|c| c.difference(cuboid))
+            .collect();
+    }
+
+    pub fn intersection(&self, cuboid: &Cuboid<N>) -> Self {
+        Self {
+            cuboids: self
+                .cuboids
+                .iter()
+                .filter_map(|c| c.intersection(cuboid))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cuboid() {
+        let cube = Cuboid::inclusive([1, 1, 1], [3, 3, 3]);
+        assert_eq!(cube.volume(), 27);
+        assert!(cube.contains([3, 1, 2]) && !cube.contains([4, 1, 2]));
+
+        let (below, above) = cube.split(0, 2);
+        assert_eq!(below.map(|c| c.volume()), Some(9));
+        assert_eq!(above.map(|c| c.volume()), Some(18));
+        assert_eq!(cube.split(1, 10), (Some(cube), None));
+
+        let other = Cuboid::new([2, 2, 0], [10, 10, 10]);
+        assert_eq!(
+            cube.intersection(&other),
+            Some(Cuboid::new([2, 2, 1], [4, 4, 4]))
+        );
+        let difference = cube.difference(&other);
+        assert_eq!(difference.iter().map(Cuboid::volume).sum::<u128>(), 27 - 12);
+        assert!(Cuboid::new([0], [0]).is_empty());
+    }
+
+    #[test]
+    fn test_boxes() {
+        // The small example of 2021 day 22, with the cubes turned on and off.
+        let mut boxes = Boxes::new();
+        boxes.insert(Cuboid::inclusive([10, 10, 10], [12, 12, 12]));
+        boxes.insert(Cuboid::inclusive([11, 11, 11], [13, 13, 13]));
+        assert_eq!(boxes.volume(), 27 + 19);
+        boxes.remove(&Cuboid::inclusive([9, 9, 9], [11, 11, 11]));
+        assert_eq!(boxes.volume(), 38);
+        boxes.insert(Cuboid::inclusive([10, 10, 10], [10, 10, 10]));
+        assert_eq!(boxes.volume(), 39);
+        assert!(boxes.contains([10, 10, 10]) && !boxes.contains([9, 9, 9]));
+
+        let corner = boxes.intersection(&Cuboid::new([12, 12, 12], [20, 20, 20]));
+        assert_eq!(corner.volume(), 8);
+        let union: Boxes<2> = [Cuboid::new([0, 0], [2, 2]), Cuboid::new([1, 1], [3, 3])]
+            .into_iter()
+            .collect();
+        assert_eq!(union.volume(), 7);
+    }
+}
@@ -0,0 +1,114 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// The shape of a sequence `x, step(x), step(step(x)), ...` that eventually
+/// repeats: after the first `tail` states, the same `period` states come round
+/// forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub tail: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The smallest number of steps that ends in the same state as `n` steps.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.tail {
+            n
+        } else {
+            self.tail + (n - self.tail) % self.period
+        }
+    }
+}
+
+/// Find the cycle with Brent's algorithm, which keeps only two states at a
+/// time but steps through the sequence about three times.
+pub fn brent<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the period by moving the tortoise to the hare at every power of
+    // two, until the hare catches up with it.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // With the hare a period ahead, the two meet where the cycle starts.
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut tail = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    Cycle { tail, period }
+}
+
+/// Remember every state until one comes round again, stopping early after
+/// `limit` steps. Returns the states in order and the cycle, if one was found.
+fn walk<S: Clone + Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    limit: usize,
+) -> (Vec<S>, Option<Cycle>) {
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    while states.len() <= limit {
+        let next = step(&states[states.len() - 1]);
+        if let Some(&tail) = seen.get(&next) {
+            let period = states.len() - tail;
+            return (states, Some(Cycle { tail, period }));
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+    (states, None)
+}
+
+/// Find the cycle by hashing every state, which steps through the sequence
+/// only once but keeps all of it.
+pub fn hashed<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S) -> Cycle {
+    walk(start, step, usize::MAX).1.unwrap()
+}
+
+/// The state after `n` steps, skipping whole periods once the sequence is
+/// seen to repeat.
+pub fn nth<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    let (mut states, cycle) = walk(start, step, n);
+    match cycle {
+        Some(cycle) => states.swap_remove(cycle.reduce(n)),
+        None => states.swap_remove(n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle() {
+        // 3, 10, 5, 16, 8, 4, 2, 1, 4, 2, 1, ...
+        let collatz = |&n: &u64| if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+        let expected = Cycle { tail: 5, period: 3 };
+        assert_eq!(brent(3, collatz), expected);
+        assert_eq!(hashed(3, collatz), expected);
+        assert_eq!(brent(1, collatz), Cycle { tail: 0, period: 3 });
+        assert_eq!(hashed(7, |&n: &u64| n), Cycle { tail: 0, period: 1 });
+
+        assert_eq!(expected.reduce(4), 4);
+        assert_eq!(expected.reduce(9), 6);
+        assert_eq!(nth(3, collatz, 2), 5);
+        assert_eq!(nth(3, collatz, 1_000_000_000), 1);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::graph::Graph;
+use std::fmt;
+
+/// Attributes given to the nodes and edges of a highlighted path.
+const HIGHLIGHT: [(&str, &str); 2] = [("color", "red"), ("penwidth", "2")];
+
+#[derive(Debug, Clone)]
+struct Node {
+    id: String,
+    label: String,
+    kind: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Edge {
+    from: String,
+    to: String,
+    label: Option<String>,
+}
+
+/// A graph to be drawn by Graphviz, written out in the DOT language by its
+/// `Display` implementation.
+#[derive(Debug, Clone, Default)]
+pub struct Dot {
+    directed: bool,
+    styles: Vec<(String, Vec<(String, String)>)>,
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    path: Vec<String>,
+}
+
+/// Quote and escape an ID or a label.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl Dot {
+    pub fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            ..Self::default()
+        }
+    }
+
+    /// Draw a graph with its vertices numbered as IDs. `node` gives the label
+    /// and kind of each vertex, and the edges are labelled with their weights.
+    pub fn from_graph(
+        graph: &Graph,
+        node: impl Fn(usize) -> (String, Option<&'static str>),
+    ) -> Self {
+        let mut result = Self::new(graph.is_directed());
+        for vertex in 0..graph.len() {
+            let (label, kind) = node(vertex);
+            result.node(&vertex.to_string(), &label, kind);
+        }
+        for vertex in 0..graph.len() {
+            for edge in graph.edges(vertex) {
+                // Undirected graphs list their edges under both ends.
+                if graph.is_directed() || vertex <= edge.target {
+                    let weight = edge.weight.to_string();
+                    result.edge(&vertex.to_string(), &edge.target.to_string(), Some(&weight));
+                }
+            }
+        }
+        result
+    }
+
+    /// Give every node of a kind the attributes `attributes`, such as
+    /// `("shape", "box")`.
+    pub fn style(&mut self, kind: &str, attributes: &[(&str, &str)]) -> &mut Self {
+        let attributes = attributes
+            .iter()
+            .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+            .collect();
+        self.styles.push((kind.to_owned(), attributes));
+        self
+    }
+
+    pub fn node(&mut self, id: &str, label: &str, kind: Option<&str>) -> &mut Self {
+        self.nodes.push(Node {
+            id: id.to_owned(),
+            label: label.to_owned(),
+            kind: kind.map(str::to_owned),
+        });
+        self
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, label: Option<&str>) -> &mut Self {
+        self.edges.push(Edge {
+            from: from.to_owned(),
+            to: to.to_owned(),
+            label: label.map(str::to_owned),
+        });
+        self
+    }
+
+    /// Mark the nodes of a path, and the edges between consecutive ones.
+    pub fn highlight<S: AsRef<str>>(&mut self, path: &[S]) -> &mut Self {
+        self.path = path.iter().map(|id| id.as_ref().to_owned()).collect();
+        self
+    }
+
+    fn on_path(&self, edge: &Edge) -> bool {
+        self.path.windows(2).any(|pair| {
+            (pair[0] == edge.from && pair[1] == edge.to)
+                || (!self.directed && pair[0] == edge.to && pair[1] == edge.from)
+        })
+    }
+}
+
+/// Write `[name=value, ...]` with the values quoted.
+fn write_attributes<'a>(
+    f: &mut fmt::Formatter<'_>,
+    attributes: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> fmt::Result {
+    let attributes: Vec<String> = attributes
+        .into_iter()
+        .map(|(name, value)| format!("{name}={}", quote(value)))
+        .collect();
+    if attributes.is_empty() {
+        Ok(())
+    } else {
+        write!(f, " [{}]", attributes.join(", "))
+    }
+}
+
+impl fmt::Display for Dot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (keyword, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        writeln!(f, "{keyword} {{")?;
+
+        for node in &self.nodes {
+            let style = self
+                .styles
+                .iter()
+                .filter(|(kind, _)| node.kind.as_ref() == Some(kind))
+                .flat_map(|(_, attributes)| attributes)
+                .map(|(name, value)| (name.as_str(), value.as_str()));
+            let highlight = HIGHLIGHT
+                .iter()
+                .copied()
+                .filter(|_| self.path.contains(&node.id));
+            write!(f, "    {}", quote(&node.id))?;
+            write_attributes(
+                f,
+                [("label", node.label.as_str())]
+                    .into_iter()
+                    .chain(style)
+                    .chain(highlight),
+            )?;
+            writeln!(f, ";")?;
+        }
+
+        for edge in &self.edges {
+            let highlight = HIGHLIGHT.iter().copied().filter(|_| self.on_path(edge));
+            write!(f, "    {} {arrow} {}", quote(&edge.from), quote(&edge.to))?;
+            write_attributes(
+                f,
+                edge.label
+                    .iter()
+                    .map(|label| ("label", label.as_str()))
+                    .chain(highlight),
+            )?;
+            writeln!(f, ";")?;
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot() {
+        let mut dot = Dot::new(true);
+        dot.style("gate", &[("shape", "box")])
+            .node("a", "say \"hi\"", Some("gate"))
+            .node("b", "b", None)
+            .node("c", "c", None)
+            .edge("a", "b", Some("x<3"))
+            .edge("b", "c", None)
+            .edge("a", "c", None)
+            .highlight(&["a", "b"]);
+        assert_eq!(
+            dot.to_string(),
+            r#"digraph {
+    "a" [label="say \"hi\"", shape="box", color="red", penwidth="2"];
+    "b" [label="b", color="red", penwidth="2"];
+    "c" [label="c"];
+    "a" -> "b" [label="x<3", color="red", penwidth="2"];
+    "b" -> "c";
+    "a" -> "c";
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_from_graph() {
+        let mut graph = Graph::new(false);
+        for _ in 0..3 {
+            graph.add_vertex();
+        }
+        graph.add_edge(0, 1, 5);
+        graph.add_edge(2, 1, 7);
+        let mut dot = Dot::from_graph(&graph, |v| (format!("v{v}"), (v == 0).then_some("first")));
+        dot.style("first", &[("shape", "box")])
+            .highlight(&["1", "2"]);
+        assert_eq!(
+            dot.to_string(),
+            r#"graph {
+    "0" [label="v0", shape="box"];
+    "1" [label="v1", color="red", penwidth="2"];
+    "2" [label="v2", color="red", penwidth="2"];
+    "0" -- "1" [label="5"];
+    "1" -- "2" [label="7", color="red", penwidth="2"];
+}
+"#
+        );
+    }
+}
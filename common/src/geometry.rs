@@ -0,0 +1,372 @@
+use std::ops::{Add, Mul, Sub};
+
+/// A unit step on a grid, as an `(dx, dy)` offset where `y` grows downwards.
+pub trait Step: Copy {
+    fn delta(self) -> (isize, isize);
+}
+
+/// One of the four axis-aligned directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting upwards.
+    pub const ALL: [Direction; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Parse an arrow (`^`, `v`, `<`, `>`) or a letter (`U`, `D`, `L`, `R`).
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'U' => Some(Self::Up),
+            'v' | 'D' => Some(Self::Down),
+            '<' | 'L' => Some(Self::Left),
+            '>' | 'R' => Some(Self::Right),
+            _ => None,
+        }
+    }
+
+    pub fn clockwise(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn counter_clockwise(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Left => Self::Down,
+            Self::Down => Self::Right,
+            Self::Right => Self::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// The two directions at a right angle to this one.
+    pub fn perpendicular(self) -> [Self; 2] {
+        match self {
+            Self::Up | Self::Down => [Self::Left, Self::Right],
+            Self::Left | Self::Right => [Self::Up, Self::Down],
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    /// Position of this direction in `ALL`, for use as an array index.
+    pub fn index(self) -> usize {
+        match self {
+            Self::Up => 0,
+            Self::Right => 1,
+            Self::Down => 2,
+            Self::Left => 3,
+        }
+    }
+}
+
+impl Step for Direction {
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+        }
+    }
+}
+
+/// One of the eight compass directions, including the diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// All directions in clockwise order, starting north.
+    pub const ALL: [Direction8; 8] = [
+        Self::N,
+        Self::NE,
+        Self::E,
+        Self::SE,
+        Self::S,
+        Self::SW,
+        Self::W,
+        Self::NW,
+    ];
+
+    fn from_index(i: usize) -> Self {
+        Self::ALL[i % 8]
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Rotate clockwise by 45 degrees.
+    pub fn clockwise(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// Rotate counter-clockwise by 45 degrees.
+    pub fn counter_clockwise(self) -> Self {
+        Self::from_index(self.index() + 7)
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::from_index(self.index() + 4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        matches!(self, Self::NE | Self::SE | Self::SW | Self::NW)
+    }
+}
+
+impl Step for Direction8 {
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Self::N => (0, -1),
+            Self::NE => (1, -1),
+            Self::E => (1, 0),
+            Self::SE => (1, 1),
+            Self::S => (0, 1),
+            Self::SW => (-1, 1),
+            Self::W => (-1, 0),
+            Self::NW => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(d: Direction) -> Self {
+        match d {
+            Direction::Up => Self::N,
+            Direction::Right => Self::E,
+            Direction::Down => Self::S,
+            Direction::Left => Self::W,
+        }
+    }
+}
+
+/// A position on a grid, with `x` the column and `y` the row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Coord {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Move by an offset. Returns `None` if the result would be negative.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    /// Take a step, assuming it does not go below zero.
+    pub fn step(self, d: impl Step) -> Self {
+        self.checked_step(d)
+            .unwrap_or_else(|| panic!("step from {self:?} goes out of bounds"))
+    }
+
+    /// Take a step. Returns `None` if the result would be negative.
+    pub fn checked_step(self, d: impl Step) -> Option<Self> {
+        let (dx, dy) = d.delta();
+        self.offset(dx, dy)
+    }
+
+    /// Take a step. Returns `None` if the result would leave a grid of the
+    /// given size.
+    pub fn step_within(self, d: impl Step, width: usize, height: usize) -> Option<Self> {
+        self.checked_step(d).filter(|c| c.x < width && c.y < height)
+    }
+
+    /// The non-negative coordinates directly above, below, left and right.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.checked_step(d))
+    }
+
+    /// The non-negative coordinates surrounding this one, including diagonals.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |d| self.checked_step(d))
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+/// A position on an unbounded plane, which may be negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ICoord {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl ICoord {
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn step(self, d: impl Step) -> Self {
+        self.step_n(d, 1)
+    }
+
+    /// Take `n` steps in the same direction.
+    pub fn step_n(self, d: impl Step, n: isize) -> Self {
+        let (dx, dy) = d.delta();
+        Self {
+            x: self.x + dx * n,
+            y: self.y + dy * n,
+        }
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl Add for ICoord {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl Sub for ICoord {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl Mul<isize> for ICoord {
+    type Output = Self;
+
+    fn mul(self, n: isize) -> Self {
+        Self {
+            x: self.x * n,
+            y: self.y * n,
+        }
+    }
+}
+
+impl TryFrom<Coord> for ICoord {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(c: Coord) -> Result<Self, Self::Error> {
+        Ok(Self {
+            x: c.x.try_into()?,
+            y: c.y.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<ICoord> for Coord {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(c: ICoord) -> Result<Self, Self::Error> {
+        Ok(Self {
+            x: c.x.try_into()?,
+            y: c.y.try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotations() {
+        for d in Direction::ALL {
+            assert_eq!(d.clockwise().counter_clockwise(), d);
+            assert_eq!(d.clockwise().clockwise(), d.reverse());
+            assert!(!d.perpendicular().contains(&d));
+            assert_eq!(Direction::ALL[d.index()], d);
+        }
+        for d in Direction8::ALL {
+            assert_eq!(d.clockwise().counter_clockwise(), d);
+            assert_eq!(
+                d.clockwise().clockwise().clockwise().clockwise(),
+                d.reverse()
+            );
+        }
+        assert_eq!(Direction8::NW.clockwise(), Direction8::N);
+    }
+
+    #[test]
+    fn test_checked_moves() {
+        let origin = Coord::new(0, 0);
+        assert_eq!(origin.checked_step(Direction::Up), None);
+        assert_eq!(origin.checked_step(Direction8::SE), Some(Coord::new(1, 1)));
+        assert_eq!(Coord::new(2, 1).step_within(Direction::Right, 3, 3), None);
+        assert_eq!(origin.neighbours4().count(), 2);
+        assert_eq!(origin.neighbours8().count(), 3);
+        assert_eq!(Coord::new(1, 1).neighbours8().count(), 8);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = ICoord::new(-2, 3);
+        let b = ICoord::new(1, -1);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Coord::new(1, 5).manhattan(Coord::new(4, 2)), 6);
+        assert_eq!(a.step_n(Direction::Right, 3), ICoord::new(1, 3));
+    }
+}
@@ -0,0 +1,460 @@
+use crate::{
+    geometry::{Coord, Direction},
+    grid::Grid,
+};
+use std::{
+    collections::HashMap,
+    iter,
+    num::NonZero,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+/// A weighted edge to the vertex numbered `target`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge {
+    pub target: usize,
+    pub weight: usize,
+}
+
+/// A weighted graph with vertices numbered `0..len()`, stored as adjacency
+/// lists. An undirected graph lists every edge under both of its ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    edges: Vec<Vec<Edge>>,
+    directed: bool,
+}
+
+impl Graph {
+    pub fn new(directed: bool) -> Self {
+        Self {
+            edges: Vec::new(),
+            directed,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Add a vertex without edges and return its number.
+    pub fn add_vertex(&mut self) -> usize {
+        self.edges.push(Vec::new());
+        self.edges.len() - 1
+    }
+
+    /// Add an edge, which also goes from `to` to `from` if the graph is
+    /// undirected.
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: usize) {
+        self.edges[from].push(Edge { target: to, weight });
+        if !self.directed && from != to {
+            self.edges[to].push(Edge {
+                target: from,
+                weight,
+            });
+        }
+    }
+
+    /// The edges leaving `vertex`.
+    pub fn edges(&self, vertex: usize) -> &[Edge] {
+        &self.edges[vertex]
+    }
+}
+
+/// A maze compressed into a graph: the vertices are its junctions and the
+/// terminals it was built with, and every corridor between two of them is an
+/// edge weighted by its length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Junctions {
+    pub graph: Graph,
+    /// The cell of each vertex. The terminals come first, in the order they
+    /// were given.
+    pub coords: Vec<Coord>,
+    vertices: HashMap<Coord, usize>,
+}
+
+impl Junctions {
+    /// Compress the cells for which `passable` holds, which can be walked in
+    /// any direction. Only the part of the maze reachable from a terminal is
+    /// included.
+    pub fn new<T>(grid: &Grid<T>, terminals: &[Coord], passable: impl Fn(&T) -> bool) -> Self {
+        Self::build(grid, terminals, passable, |_, _| true, false)
+    }
+
+    /// Like `new`, but a cell may only be entered moving in a direction for
+    /// which `can_enter` holds, such as down a one-way slope.
+    pub fn directed<T>(
+        grid: &Grid<T>,
+        terminals: &[Coord],
+        passable: impl Fn(&T) -> bool,
+        can_enter: impl Fn(&T, Direction) -> bool,
+    ) -> Self {
+        Self::build(grid, terminals, passable, can_enter, true)
+    }
+
+    fn build<T>(
+        grid: &Grid<T>,
+        terminals: &[Coord],
+        passable: impl Fn(&T) -> bool,
+        can_enter: impl Fn(&T, Direction) -> bool,
+        directed: bool,
+    ) -> Self {
+        let open = |c: Coord| {
+            Direction::ALL
+                .into_iter()
+                .filter_map(move |d| Some((d, grid.step(c, d)?)))
+                .filter(|&(_, n)| passable(&grid[n]))
+        };
+
+        let mut graph = Graph::new(directed);
+        let mut coords = Vec::new();
+        let mut indices = HashMap::new();
+        for &terminal in terminals {
+            indices.entry(terminal).or_insert_with(|| {
+                coords.push(terminal);
+                graph.add_vertex()
+            });
+        }
+
+        // Walk every corridor leaving every vertex, adding the junctions at
+        // their far ends as they are found.
+        let mut next = 0;
+        while let Some(&start) = coords.get(next) {
+            let from = next;
+            next += 1;
+            for (direction, first) in open(start) {
+                if !can_enter(&grid[first], direction) {
+                    continue;
+                }
+                let (mut previous, mut current, mut weight) = (start, first, 1);
+                let to = loop {
+                    if let Some(&index) = indices.get(&current) {
+                        break Some(index);
+                    }
+                    let mut onward = open(current).filter(|&(_, n)| n != previous);
+                    let Some((direction, n)) = onward.next() else {
+                        break None;
+                    };
+                    if onward.next().is_some() {
+                        indices.insert(current, graph.add_vertex());
+                        coords.push(current);
+                        continue;
+                    }
+                    if !can_enter(&grid[n], direction) {
+                        break None;
+                    }
+                    (previous, current, weight) = (current, n, weight + 1);
+                };
+                // Undirected corridors are walked from both ends but added
+                // once, and loops back to the start are of no use.
+                match to {
+                    Some(to) if from != to && (directed || from < to) => {
+                        graph.add_edge(from, to, weight);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Self {
+            graph,
+            coords,
+            vertices: indices,
+        }
+    }
+
+    /// The vertex at `coord`, if it is a junction or a terminal.
+    pub fn vertex(&self, coord: Coord) -> Option<usize> {
+        self.vertices.get(&coord).copied()
+    }
+}
+
+/// A path that visits no vertex twice, with the total weight of its edges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimplePath {
+    pub length: usize,
+    pub vertices: Vec<usize>,
+}
+
+/// The vertices in a bitmask.
+fn bits(mut mask: u128) -> impl Iterator<Item = usize> {
+    iter::from_fn(move || {
+        let bit = mask.trailing_zeros() as usize;
+        mask &= mask.checked_sub(1)?;
+        Some(bit)
+    })
+}
+
+/// A depth first search for the longest simple path to `end`, keeping the
+/// visited vertices in a bitmask.
+struct LongestPath<'a> {
+    graph: &'a Graph,
+    end: usize,
+    successors: Vec<u128>,
+    /// The heaviest edge into each vertex, the most that visiting it can add.
+    heaviest_in: Vec<usize>,
+    /// The only vertex with an edge to the end, if there is just one. A path
+    /// that leaves it for any other vertex can never reach the end.
+    gate: Option<usize>,
+    /// One more than the length of the longest path found by any thread, or
+    /// zero if none was found yet.
+    best: AtomicUsize,
+}
+
+impl<'a> LongestPath<'a> {
+    fn new(graph: &'a Graph, end: usize) -> Self {
+        assert!(
+            graph.len() <= 128,
+            "longest paths need at most 128 vertices, got {}",
+            graph.len()
+        );
+        let mut successors = vec![0; graph.len()];
+        let mut heaviest_in = vec![0; graph.len()];
+        for (vertex, mask) in successors.iter_mut().enumerate() {
+            for edge in graph.edges(vertex) {
+                *mask |= 1 << edge.target;
+                heaviest_in[edge.target] = heaviest_in[edge.target].max(edge.weight);
+            }
+        }
+        let mut into_end = (0..graph.len()).filter(|&v| v != end && successors[v] & 1 << end != 0);
+        let gate = into_end.next().filter(|_| into_end.next().is_none());
+
+        Self {
+            graph,
+            end,
+            successors,
+            heaviest_in,
+            gate,
+            best: AtomicUsize::new(0),
+        }
+    }
+
+    /// The most that can still be added to a path at `current` without
+    /// revisiting `visited`, or `None` if the end is out of reach.
+    fn bound(&self, current: usize, visited: u128) -> Option<usize> {
+        let mut reached = 0;
+        let mut frontier = 1 << current;
+        while frontier != 0 {
+            let next = bits(frontier).fold(0, |mask, v| mask | self.successors[v]);
+            frontier = next & !visited & !reached;
+            reached |= frontier;
+        }
+        (reached & 1 << self.end != 0).then(|| bits(reached).map(|v| self.heaviest_in[v]).sum())
+    }
+
+    /// The edges a path at `current` can be extended along.
+    fn steps(&self, current: usize, visited: u128) -> impl Iterator<Item = &'a Edge> + '_ {
+        let graph = self.graph;
+        graph.edges(current).iter().filter(move |edge| {
+            visited & 1 << edge.target == 0
+                && (self.gate != Some(current) || edge.target == self.end)
+        })
+    }
+
+    /// Extend `path` in every possible way, keeping the best path found in
+    /// `found` if it beats those of the other threads.
+    fn extend(
+        &self,
+        path: &mut Vec<usize>,
+        visited: u128,
+        length: usize,
+        found: &mut Option<SimplePath>,
+    ) {
+        let current = path[path.len() - 1];
+        if current == self.end {
+            if self.best.fetch_max(length + 1, Ordering::Relaxed) < length + 1 {
+                *found = Some(SimplePath {
+                    length,
+                    vertices: path.clone(),
+                });
+            }
+            return;
+        }
+        let Some(bound) = self.bound(current, visited) else {
+            return;
+        };
+        if length + bound < self.best.load(Ordering::Relaxed) {
+            return;
+        }
+        for edge in self.steps(current, visited) {
+            path.push(edge.target);
+            self.extend(
+                path,
+                visited | 1 << edge.target,
+                length + edge.weight,
+                found,
+            );
+            path.pop();
+        }
+    }
+}
+
+/// The longest simple path from `start` to `end` in a graph of at most 128
+/// vertices, or `None` if there is no path.
+pub fn longest_simple_path(graph: &Graph, start: usize, end: usize) -> Option<SimplePath> {
+    let search = LongestPath::new(graph, end);
+    let mut found = None;
+    search.extend(&mut vec![start], 1 << start, 0, &mut found);
+    found
+}
+
+/// Like `longest_simple_path`, but the search is split into the branches a
+/// few steps from the start, which are explored on all cores.
+pub fn longest_simple_path_parallel(graph: &Graph, start: usize, end: usize) -> Option<SimplePath> {
+    let search = LongestPath::new(graph, end);
+    let threads = thread::available_parallelism().map_or(1, NonZero::get);
+
+    // Expand the branches level by level until there are enough of them to
+    // keep every thread busy even if some are pruned quickly.
+    let mut branches = vec![(vec![start], 1 << start, 0)];
+    while branches.len() < 4 * threads {
+        let mut next = Vec::new();
+        for (path, visited, length) in &branches {
+            let current = path[path.len() - 1];
+            if current == end {
+                next.push((path.clone(), *visited, *length));
+                continue;
+            }
+            for edge in search.steps(current, *visited) {
+                let mut path = path.clone();
+                path.push(edge.target);
+                next.push((path, visited | 1 << edge.target, length + edge.weight));
+            }
+        }
+        if next.len() <= branches.len() {
+            branches = next;
+            break;
+        }
+        branches = next;
+    }
+
+    let taken = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut found = None;
+                    while let Some((path, visited, length)) =
+                        branches.get(taken.fetch_add(1, Ordering::Relaxed))
+                    {
+                        search.extend(&mut path.clone(), *visited, *length, &mut found);
+                    }
+                    found
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .filter_map(|worker| worker.join().unwrap())
+            .max_by_key(|path: &SimplePath| path.length)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+#.#####
+#...>.#
+#.#.#.#
+#.....#
+###.###
+";
+
+    fn targets(graph: &Graph, vertex: usize) -> Vec<(usize, usize)> {
+        let mut result: Vec<_> = graph
+            .edges(vertex)
+            .iter()
+            .map(|e| (e.target, e.weight))
+            .collect();
+        result.sort_unstable();
+        result
+    }
+
+    #[test]
+    fn test_junctions() {
+        let grid = Grid::parse_chars(MAZE).unwrap();
+        let (start, end) = (Coord::new(1, 0), Coord::new(3, 4));
+        let junctions = Junctions::new(&grid, &[start, end], |&c| c != '#');
+        let graph = &junctions.graph;
+
+        assert!(!graph.is_directed());
+        assert_eq!(
+            junctions.coords,
+            [
+                start,
+                end,
+                Coord::new(1, 1),
+                Coord::new(3, 3),
+                Coord::new(3, 1)
+            ]
+        );
+        assert_eq!(junctions.vertex(Coord::new(3, 1)), Some(4));
+        assert_eq!(junctions.vertex(Coord::new(2, 1)), None);
+        assert_eq!(targets(graph, 0), [(2, 1)]);
+        assert_eq!(targets(graph, 1), [(3, 1)]);
+        assert_eq!(targets(graph, 2), [(0, 1), (3, 4), (4, 2)]);
+        assert_eq!(targets(graph, 3), [(1, 1), (2, 4), (4, 2), (4, 6)]);
+        assert_eq!(targets(graph, 4), [(2, 2), (3, 2), (3, 6)]);
+    }
+
+    #[test]
+    fn test_directed_junctions() {
+        let grid = Grid::parse_chars(MAZE).unwrap();
+        let (start, end) = (Coord::new(1, 0), Coord::new(3, 4));
+        let junctions = Junctions::directed(
+            &grid,
+            &[start, end],
+            |&c| c != '#',
+            |&c, d| Direction::from_char(c).is_none_or(|slope| slope == d),
+        );
+        let graph = &junctions.graph;
+
+        assert!(graph.is_directed());
+        // The slope only lets the long corridor be walked clockwise.
+        assert_eq!(targets(graph, 3), [(1, 1), (2, 4), (4, 2)]);
+        assert_eq!(targets(graph, 4), [(2, 2), (3, 2), (3, 6)]);
+
+        // A corridor from a junction back to itself is left out.
+        let grid = Grid::parse_chars("#####\n#...#\n..#.#\n#...#\n#####").unwrap();
+        let junctions = Junctions::directed(&grid, &[Coord::new(0, 2)], |&c| c != '#', |_, _| true);
+        assert_eq!(junctions.vertex(Coord::new(1, 2)), Some(1));
+        assert_eq!(targets(&junctions.graph, 0), [(1, 1)]);
+        assert_eq!(targets(&junctions.graph, 1), [(0, 1)]);
+    }
+
+    #[test]
+    fn test_longest_simple_path() {
+        let grid = Grid::parse_chars(MAZE).unwrap();
+        let (start, end) = (Coord::new(1, 0), Coord::new(3, 4));
+        let graph = Junctions::new(&grid, &[start, end], |&c| c != '#').graph;
+        let expected = SimplePath {
+            length: 10,
+            vertices: vec![0, 2, 4, 3, 1],
+        };
+        assert_eq!(longest_simple_path(&graph, 0, 1), Some(expected.clone()));
+        assert_eq!(longest_simple_path_parallel(&graph, 0, 1), Some(expected));
+        assert_eq!(longest_simple_path(&graph, 0, 0).unwrap().length, 0);
+
+        // The corridor out of the start cannot be walked back up.
+        let mut graph = Graph::new(true);
+        for _ in 0..4 {
+            graph.add_vertex();
+        }
+        graph.add_edge(0, 1, 3);
+        graph.add_edge(1, 2, 4);
+        graph.add_edge(2, 1, 4);
+        assert_eq!(longest_simple_path(&graph, 0, 2).unwrap().length, 7);
+        assert_eq!(longest_simple_path(&graph, 2, 0), None);
+        assert_eq!(longest_simple_path_parallel(&graph, 0, 3), None);
+    }
+}
@@ -0,0 +1,270 @@
+use crate::geometry::{Coord, Direction, Direction8, Step};
+use anyhow::{anyhow, Result};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Parse a grid from text with one row per line, converting each character
+    /// into a cell. Fails if the rows are not all of the same length.
+    pub fn parse(s: &str, mut f: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in s.lines() {
+            let before = cells.len();
+            for c in line.chars() {
+                cells.push(f(c)?);
+            }
+
+            let row_width = cells.len() - before;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(anyhow!(
+                    "grid is not rectangular: row {height} has width {row_width}"
+                ));
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    /// Create a grid from its cells in row-major order.
+    pub fn from_cells(cells: Vec<T>, width: usize, height: usize) -> Result<Self> {
+        if cells.len() != width * height {
+            return Err(anyhow!(
+                "expected {} cells for a {width}x{height} grid, got {}",
+                width * height,
+                cells.len()
+            ));
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, c: Coord) -> bool {
+        c.x < self.width && c.y < self.height
+    }
+
+    pub fn get(&self, c: Coord) -> Option<&T> {
+        if self.in_bounds(c) {
+            Some(&self.cells[c.y * self.width + c.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, c: Coord) -> Option<&mut T> {
+        if self.in_bounds(c) {
+            Some(&mut self.cells[c.y * self.width + c.x])
+        } else {
+            None
+        }
+    }
+
+    /// Take a step from a coordinate. Returns `None` if it leaves the grid.
+    pub fn step(&self, c: Coord, d: impl Step) -> Option<Coord> {
+        c.step_within(d, self.width, self.height)
+    }
+
+    /// The in-bounds coordinates directly above, below, left and right.
+    pub fn neighbours4(&self, c: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(c, d))
+    }
+
+    /// The in-bounds coordinates surrounding a coordinate, including diagonals.
+    pub fn neighbours8(&self, c: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(c, d))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // An empty grid has no rows, but chunks does not accept a size of zero.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterate over all coordinates in row-major order.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Coord { x, y }))
+    }
+
+    /// Iterate over all cells together with their coordinates in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    /// Coordinates of all cells matching a predicate, in row-major order.
+    pub fn positions<'a>(
+        &'a self,
+        mut pred: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Coord> + 'a {
+        self.iter()
+            .filter_map(move |(c, cell)| if pred(cell) { Some(c) } else { None })
+    }
+
+    /// Coordinate of the first cell matching a predicate, in row-major order.
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.positions(pred).next()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn try_map<U>(&self, f: impl FnMut(&T) -> Result<U>) -> Result<Grid<U>> {
+        Ok(Grid {
+            cells: self.cells.iter().map(f).collect::<Result<_>>()?,
+            width: self.width,
+            height: self.height,
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parse a grid of characters without converting the cells.
+    pub fn parse_chars(s: &str) -> Result<Self> {
+        Self::parse(s, Ok)
+    }
+
+    /// Find the single cell holding a marker character, such as a start or end
+    /// position. Fails if the marker occurs zero or multiple times.
+    pub fn find_marker(&self, marker: char) -> Result<Coord> {
+        let mut positions = self.positions(|&c| c == marker);
+        let result = positions
+            .next()
+            .ok_or_else(|| anyhow!("marker '{marker}' not found"))?;
+        if positions.next().is_some() {
+            return Err(anyhow!("marker '{marker}' occurs multiple times"));
+        }
+        Ok(result)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, c: Coord) -> &T {
+        self.get(c).unwrap_or_else(|| {
+            panic!(
+                "{c:?} out of bounds for {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, c: Coord) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(c)
+            .unwrap_or_else(|| panic!("{c:?} out of bounds for {width}x{height} grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#.#\n..#\n", |c| Ok(c == '#')).unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(Coord::new(2, 1)), Some(&true));
+        assert_eq!(grid.get(Coord::new(1, 0)), Some(&false));
+        assert_eq!(grid.get(Coord::new(3, 0)), None);
+        assert_eq!(grid.get(Coord::new(0, 2)), None);
+    }
+
+    #[test]
+    fn test_parse_not_rectangular() {
+        assert!(Grid::parse_chars("abc\nde\n").is_err());
+        assert!(Grid::parse("ab\nc?\n", |c| {
+            c.is_ascii_lowercase()
+                .then_some(c)
+                .ok_or(anyhow!("invalid character"))
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse_chars("ab\ncd\nef").unwrap();
+        let rows: Vec<String> = grid.rows().map(|r| r.iter().collect()).collect();
+        assert_eq!(rows, ["ab", "cd", "ef"]);
+        let columns: Vec<String> = grid.columns().map(Iterator::collect).collect();
+        assert_eq!(columns, ["ace", "bdf"]);
+        assert_eq!(Grid::parse_chars("").unwrap().rows().count(), 0);
+    }
+
+    #[test]
+    fn test_find_marker() {
+        let grid = Grid::parse_chars("S.#\n.#E\n..E").unwrap();
+        assert_eq!(grid.find_marker('S').unwrap(), Coord::new(0, 0));
+        assert!(grid.find_marker('E').is_err());
+        assert!(grid.find_marker('@').is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 2, ());
+        assert_eq!(grid.neighbours4(Coord::new(2, 1)).count(), 2);
+        assert_eq!(grid.neighbours8(Coord::new(1, 0)).count(), 5);
+        assert_eq!(grid.step(Coord::new(1, 1), Direction::Down), None);
+    }
+}
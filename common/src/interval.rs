@@ -0,0 +1,358 @@
+use anyhow::{anyhow, Result};
+use std::{
+    fmt::Debug,
+    iter::Sum,
+    ops::{Add, Range, RangeInclusive, Sub},
+};
+
+/// Integers that can bound an interval.
+pub trait Bound: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Sum {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_bound {
+    ($($t:ty),*) => {
+        $(impl Bound for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+        })*
+    };
+}
+
+impl_bound!(i32, i64, i128, isize, u32, u64, u128, usize);
+
+/// A set of integers stored as sorted half-open ranges, which neither overlap
+/// nor touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Bound> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut ranges: Vec<Range<T>> = iter.into_iter().filter(|r| !r.is_empty()).collect();
+        ranges.sort_unstable_by_key(|r| r.start);
+
+        let mut result: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match result.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => result.push(range),
+            }
+        }
+        Self { ranges: result }
+    }
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The set of the integers in `ranges`. Fails if one ends at the largest
+    /// value of `T`, which no half-open range can include.
+    pub fn from_inclusive(ranges: impl IntoIterator<Item = RangeInclusive<T>>) -> Result<Self>
+    where
+        T: Debug,
+    {
+        ranges
+            .into_iter()
+            .map(|r| {
+                let end = r
+                    .end()
+                    .checked_add(T::ONE)
+                    .ok_or(anyhow!("range {r:?} ends at the largest value"))?;
+                Ok(*r.start()..end)
+            })
+            .collect()
+    }
+
+    /// The ranges in increasing order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> T {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+
+    /// Whether `x` is in the set, found by binary search.
+    pub fn contains(&self, x: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= x);
+        self.ranges.get(i).is_some_and(|r| r.start <= x)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        *self = self.ranges.iter().cloned().chain([range]).collect();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            // The range that ends first cannot meet anything further on.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => {
+                self.intersection(&other.complement(first.start..last.end))
+            }
+            _ => Self::new(),
+        }
+    }
+
+    /// The integers in `bounds` that are not in the set.
+    pub fn complement(&self, bounds: Range<T>) -> Self {
+        let mut ranges = Vec::new();
+        let mut start = bounds.start;
+        for range in &self.ranges {
+            if range.start > start {
+                ranges.push(start..range.start.min(bounds.end));
+            }
+            start = start.max(range.end);
+            if start >= bounds.end {
+                break;
+            }
+        }
+        if start < bounds.end {
+            ranges.push(start..bounds.end);
+        }
+        ranges.retain(|r| !r.is_empty());
+        Self { ranges }
+    }
+
+    /// The ranges between consecutive ranges of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges
+            .windows(2)
+            .map(|pair| pair[0].end..pair[1].start)
+    }
+}
+
+/// A function on the integers that adds a constant offset on each of some
+/// disjoint ranges, and leaves other integers as they are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetMap<T> {
+    /// Sorted by start, and none with a zero offset.
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T> Default for OffsetMap<T> {
+    fn default() -> Self {
+        Self { pieces: Vec::new() }
+    }
+}
+
+impl<T: Bound + Debug> OffsetMap<T> {
+    /// The map adding `offset` to every integer in its range. Fails if the
+    /// ranges overlap.
+    pub fn new(pieces: impl IntoIterator<Item = (Range<T>, T)>) -> Result<Self> {
+        let mut pieces: Vec<(Range<T>, T)> = pieces
+            .into_iter()
+            .filter(|(range, offset)| !range.is_empty() && *offset != T::ZERO)
+            .collect();
+        pieces.sort_unstable_by_key(|(range, _)| range.start);
+        if let Some(pair) = pieces
+            .windows(2)
+            .find(|pair| pair[0].0.end > pair[1].0.start)
+        {
+            return Err(anyhow!(
+                "ranges {:?} and {:?} overlap",
+                pair[0].0,
+                pair[1].0
+            ));
+        }
+        Ok(Self { pieces })
+    }
+
+    /// The map that leaves every integer as it is.
+    pub fn identity() -> Self {
+        Self::default()
+    }
+
+    /// The ranges and offsets that are not the identity, in increasing order.
+    pub fn pieces(&self) -> &[(Range<T>, T)] {
+        &self.pieces
+    }
+
+    pub fn apply(&self, x: T) -> T {
+        let i = self.pieces.partition_point(|(range, _)| range.end <= x);
+        match self.pieces.get(i) {
+            Some((range, offset)) if range.start <= x => x + *offset,
+            _ => x,
+        }
+    }
+
+    /// Cut `range` where the offset changes, giving each piece with its
+    /// offset.
+    fn split(&self, range: Range<T>) -> Vec<(Range<T>, T)> {
+        let mut result = Vec::new();
+        let mut start = range.start;
+        let first = self.pieces.partition_point(|(piece, _)| piece.end <= start);
+        for (piece, offset) in &self.pieces[first..] {
+            if start >= range.end {
+                break;
+            }
+            if piece.start > start {
+                result.push((start..piece.start.min(range.end), T::ZERO));
+            }
+            let (from, to) = (piece.start.max(start), piece.end.min(range.end));
+            if from < to {
+                result.push((from..to, *offset));
+            }
+            start = start.max(piece.end);
+        }
+        if start < range.end {
+            result.push((start..range.end, T::ZERO));
+        }
+        result
+    }
+
+    /// The integers that have an offset.
+    fn domain(&self) -> IntervalSet<T> {
+        self.pieces.iter().map(|(range, _)| range.clone()).collect()
+    }
+
+    /// The map that applies `self` and then `other`.
+    pub fn compose(&self, other: &Self) -> Self {
+        let mut pieces = Vec::new();
+        for (range, offset) in &self.pieces {
+            let image = range.start + *offset..range.end + *offset;
+            for (sub, next) in other.split(image) {
+                pieces.push((sub.start - *offset..sub.end - *offset, *offset + next));
+            }
+        }
+        // Where `self` is the identity, only `other` moves anything.
+        let domain = self.domain();
+        for (range, offset) in &other.pieces {
+            let outside = IntervalSet::from_iter([range.clone()]).difference(&domain);
+            pieces.extend(outside.ranges().iter().map(|sub| (sub.clone(), *offset)));
+        }
+        Self::new(pieces).expect("composed pieces are disjoint")
+    }
+
+    /// The integers that some integer in `set` is mapped to.
+    pub fn image(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.ranges()
+            .iter()
+            .flat_map(|range| self.split(range.clone()))
+            .map(|(range, offset)| range.start + offset..range.end + offset)
+            .collect()
+    }
+
+    /// The integers that are mapped into `set`.
+    pub fn preimage(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges: Vec<Range<T>> = set.difference(&self.domain()).ranges().to_vec();
+        for (range, offset) in &self.pieces {
+            let image = IntervalSet::from_iter([range.start + *offset..range.end + *offset]);
+            ranges.extend(
+                image
+                    .intersection(set)
+                    .ranges()
+                    .iter()
+                    .map(|r| r.start - *offset..r.end - *offset),
+            );
+        }
+        ranges.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_interval_set() {
+        let set: IntervalSet<i64> = [5..8, 1..3, 2..4, 8..10, 12..12].into_iter().collect();
+        assert_eq!(set.ranges(), &[1..4, 5..10]);
+        assert_eq!(set, IntervalSet::from_inclusive([1..=3, 5..=9]).unwrap());
+        assert!(IntervalSet::from_inclusive([0..=u32::MAX]).is_err());
+        assert_eq!(
+            IntervalSet::from_inclusive([0..=u32::MAX - 1])
+                .unwrap()
+                .len(),
+            u32::MAX
+        );
+        assert_eq!(set.len(), 8);
+        assert!(set.contains(1) && set.contains(3) && set.contains(9));
+        assert!(!set.contains(0) && !set.contains(4) && !set.contains(10));
+        assert_eq!(set.gaps().collect::<Vec<_>>(), vec![4..5]);
+        assert!(IntervalSet::<u32>::new().is_empty());
+
+        let other: IntervalSet<i64> = [0..2, 3..6, 9..20].into_iter().collect();
+        assert_eq!(set.union(&other).ranges(), &[0..20]);
+        assert_eq!(
+            set.intersection(&other).ranges(),
+            &[1..2, 3..4, 5..6, 9..10]
+        );
+        assert_eq!(set.difference(&other).ranges(), &[2..3, 6..9]);
+        assert_eq!(set.complement(0..12).ranges(), &[0..1, 4..5, 10..12]);
+        assert_eq!(set.complement(2..7).ranges(), &[4..5]);
+
+        let mut set = set;
+        set.insert(4..5);
+        assert_eq!(set.ranges(), &[1..10]);
+    }
+
+    #[test]
+    fn test_offset_map() {
+        // 50..98 moves up 2, and 98..100 wraps round to 50..52.
+        let soil = OffsetMap::new([(98..100, -48), (50..98, 2)]).unwrap();
+        assert_eq!(soil.apply(79), 81);
+        assert_eq!(soil.apply(99), 51);
+        assert_eq!(soil.apply(14), 14);
+        assert!(OffsetMap::new([(0..10, 1), (5..15, 2)]).is_err());
+
+        let fertilizer = OffsetMap::new([(15..52, -15), (52..54, -15), (0..15, 39)]).unwrap();
+        let both = soil.compose(&fertilizer);
+        for x in 0..120 {
+            assert_eq!(both.apply(x), fertilizer.apply(soil.apply(x)));
+        }
+        assert_eq!(soil.compose(&OffsetMap::identity()), soil);
+
+        let seeds: IntervalSet<i64> = [79..93, 95..100].into_iter().collect();
+        let image = soil.image(&seeds);
+        assert_eq!(image.ranges(), &[50..52, 81..95, 97..100]);
+        for x in 0..120 {
+            assert_eq!(
+                soil.preimage(&image).contains(x),
+                image.contains(soil.apply(x))
+            );
+        }
+    }
+}
@@ -0,0 +1,21 @@
+#![warn(clippy::pedantic)]
+#![allow(
+    clippy::missing_errors_doc,
+    clippy::missing_panics_doc,
+    clippy::must_use_candidate,
+    clippy::return_self_not_must_use
+)]
+
+pub mod boxes;
+pub mod cycle;
+pub mod dot;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod interval;
+pub mod linear;
+pub mod order;
+pub mod periodicity;
+pub mod search;
+pub mod solution;
+pub mod union_find;
@@ -0,0 +1,270 @@
+use num::{rational::Ratio, Integer, Num};
+
+/// The solutions of a system of linear equations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solutions<T> {
+    Unique(Vec<T>),
+    /// Every `particular + k_1 * basis[0] + k_2 * basis[1] + ...`.
+    Family {
+        particular: Vec<T>,
+        basis: Vec<Vec<T>>,
+    },
+    Inconsistent,
+}
+
+impl<I: Clone + Integer> Solutions<Ratio<I>> {
+    /// A solution made of integers, if there is one: the unique solution, or
+    /// one member of a family from `solve`.
+    pub fn integer(&self) -> Option<Vec<I>> {
+        let integers = |values: &[Ratio<I>]| -> Option<Vec<I>> {
+            values
+                .iter()
+                .map(|v| v.is_integer().then(|| v.to_integer()))
+                .collect()
+        };
+        match self {
+            Self::Unique(values) => integers(values),
+            Self::Family { particular, basis } => {
+                // In a family from `solve`, each basis vector is 1 at its free
+                // unknown where the others are 0, so only integer multiples
+                // of it can give integers. Write each unknown x_c as
+                // particular[c] + sum k_i * basis[i][c] with the k_i and x_c
+                // as integers, clearing the denominators of every equation.
+                let free = basis.len();
+                let mut matrix = Vec::new();
+                let mut rhs = Vec::new();
+                for (c, value) in particular.iter().enumerate() {
+                    let denominator = basis
+                        .iter()
+                        .fold(value.denom().clone(), |d, vector| d.lcm(vector[c].denom()));
+                    let scale = |r: &Ratio<I>| (r * denominator.clone()).to_integer();
+                    let mut row: Vec<I> = basis.iter().map(|vector| scale(&vector[c])).collect();
+                    row.extend((0..particular.len()).map(|x| {
+                        if x == c {
+                            I::zero() - denominator.clone()
+                        } else {
+                            I::zero()
+                        }
+                    }));
+                    matrix.push(row);
+                    rhs.push(I::zero() - scale(value));
+                }
+                let multiples = solve_integer(matrix, &rhs)?;
+
+                let mut values = particular.clone();
+                for (vector, k) in basis.iter().zip(&multiples[..free]) {
+                    for (value, v) in values.iter_mut().zip(vector) {
+                        *value = value.clone() + v * Ratio::from_integer(k.clone());
+                    }
+                }
+                integers(&values)
+            }
+            Self::Inconsistent => None,
+        }
+    }
+}
+
+/// Some integer solution of `matrix * x == rhs`, found by reducing the matrix
+/// to a lower echelon form with unimodular column operations.
+fn solve_integer<I: Clone + Integer>(mut matrix: Vec<Vec<I>>, rhs: &[I]) -> Option<Vec<I>> {
+    let unknowns = matrix.first().map_or(0, Vec::len);
+    // The column operations, so that the original matrix times `transform`
+    // is the reduced one.
+    let mut transform: Vec<Vec<I>> = (0..unknowns)
+        .map(|r| {
+            (0..unknowns)
+                .map(|c| if r == c { I::one() } else { I::zero() })
+                .collect()
+        })
+        .collect();
+    let combine = |rows: &mut [Vec<I>], i: usize, j: usize, [a, b, c, d]: [I; 4]| {
+        for row in rows {
+            let (x, y) = (row[i].clone(), row[j].clone());
+            row[i] = a.clone() * x.clone() + b.clone() * y.clone();
+            row[j] = c.clone() * x + d.clone() * y;
+        }
+    };
+
+    // Move the gcd of each row's entries from the pivot column on into the
+    // pivot column, leaving zeros to its right.
+    let mut pivots = Vec::new();
+    let mut column = 0;
+    for r in 0..matrix.len() {
+        if column == unknowns {
+            pivots.push(None);
+            continue;
+        }
+        for j in column + 1..unknowns {
+            if matrix[r][j].is_zero() {
+                continue;
+            }
+            let gcd = matrix[r][column].extended_gcd(&matrix[r][j]);
+            let a = matrix[r][column].clone() / gcd.gcd.clone();
+            let b = matrix[r][j].clone() / gcd.gcd.clone();
+            // [[x, -b], [y, a]] has determinant x * a + y * b == 1.
+            let operation = [gcd.x, gcd.y, I::zero() - b, a];
+            combine(&mut matrix, column, j, operation.clone());
+            combine(&mut transform, column, j, operation);
+        }
+        if matrix[r][column].is_zero() {
+            pivots.push(None);
+        } else {
+            pivots.push(Some(column));
+            column += 1;
+        }
+    }
+
+    // Forward substitution, with every pivot dividing exactly and every row
+    // without one already satisfied.
+    let mut reduced = vec![I::zero(); unknowns];
+    for ((row, value), pivot) in matrix.iter().zip(rhs).zip(pivots) {
+        let known = (0..column).fold(I::zero(), |sum, j| {
+            sum + row[j].clone() * reduced[j].clone()
+        });
+        let left = value.clone() - known;
+        match pivot {
+            Some(p) if left.is_multiple_of(&row[p]) => reduced[p] = left / row[p].clone(),
+            None if left.is_zero() => {}
+            _ => return None,
+        }
+    }
+
+    Some(
+        transform
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(&reduced)
+                    .fold(I::zero(), |sum, (t, w)| sum + t.clone() * w.clone())
+            })
+            .collect(),
+    )
+}
+
+/// Solve `matrix * x == rhs` exactly by Gauss-Jordan elimination, for any
+/// number of equations and unknowns. `T` should be a field, such as
+/// `Ratio<i128>` or `BigRational`.
+pub fn solve<T: Clone + Num>(matrix: &[Vec<T>], rhs: &[T]) -> Solutions<T> {
+    assert_eq!(matrix.len(), rhs.len(), "one right hand side per equation");
+    let unknowns = matrix.first().map_or(0, Vec::len);
+    let mut rows: Vec<Vec<T>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, value)| {
+            assert_eq!(row.len(), unknowns, "rows of different lengths");
+            row.iter().chain([value]).cloned().collect()
+        })
+        .collect();
+
+    // Reduce to row echelon form, with every pivot 1 and alone in its column.
+    let mut pivots = Vec::new();
+    for column in 0..unknowns {
+        let rank = pivots.len();
+        let Some(found) = (rank..rows.len()).find(|&r| !rows[r][column].is_zero()) else {
+            continue;
+        };
+        rows.swap(rank, found);
+        let pivot = rows[rank][column].clone();
+        for value in &mut rows[rank] {
+            *value = value.clone() / pivot.clone();
+        }
+        let pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r == rank || row[column].is_zero() {
+                continue;
+            }
+            let factor = row[column].clone();
+            for (value, p) in row.iter_mut().zip(&pivot_row).skip(column) {
+                *value = value.clone() - factor.clone() * p.clone();
+            }
+        }
+        pivots.push(column);
+    }
+
+    // Left over equations have no unknowns left, so must read 0 == 0.
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| !row[unknowns].is_zero())
+    {
+        return Solutions::Inconsistent;
+    }
+
+    let mut particular = vec![T::zero(); unknowns];
+    for (row, &column) in rows.iter().zip(&pivots) {
+        particular[column] = row[unknowns].clone();
+    }
+    if pivots.len() == unknowns {
+        return Solutions::Unique(particular);
+    }
+
+    let basis = (0..unknowns)
+        .filter(|column| !pivots.contains(column))
+        .map(|free| {
+            let mut vector = vec![T::zero(); unknowns];
+            vector[free] = T::one();
+            for (row, &column) in rows.iter().zip(&pivots) {
+                vector[column] = T::zero() - row[free].clone();
+            }
+            vector
+        })
+        .collect();
+    Solutions::Family { particular, basis }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratios(values: &[i64]) -> Vec<Ratio<i64>> {
+        values.iter().map(|&v| Ratio::from_integer(v)).collect()
+    }
+
+    #[test]
+    fn test_unique() {
+        // The first claw machine of 2024 day 13.
+        let matrix = [ratios(&[94, 22]), ratios(&[34, 67])];
+        let solutions = solve(&matrix, &ratios(&[8400, 5400]));
+        assert_eq!(solutions, Solutions::Unique(ratios(&[80, 40])));
+        assert_eq!(solutions.integer(), Some(vec![80, 40]));
+
+        let solutions = solve(&[ratios(&[2])], &ratios(&[3]));
+        assert_eq!(solutions, Solutions::Unique(vec![Ratio::new(3, 2)]));
+        assert_eq!(solutions.integer(), None);
+    }
+
+    #[test]
+    fn test_family() {
+        // x + 2y - z == 3 and 2x + 4y + z == 3 leave y free.
+        let matrix = [ratios(&[1, 2, -1]), ratios(&[2, 4, 1])];
+        let solutions = solve(&matrix, &ratios(&[3, 3]));
+        assert_eq!(
+            solutions,
+            Solutions::Family {
+                particular: ratios(&[2, 0, -1]),
+                basis: vec![ratios(&[-2, 1, 0])],
+            }
+        );
+        assert_eq!(solutions.integer(), Some(vec![2, 0, -1]));
+
+        // 2x + 4y == 6 has integer solutions, but 2x + 4y == 5 and
+        // 2x + 4y - 6z == 3 do not.
+        let solutions = solve(&[ratios(&[2, 4])], &ratios(&[6]));
+        let [x, y] = solutions.integer().unwrap()[..] else {
+            panic!("two unknowns");
+        };
+        assert_eq!(2 * x + 4 * y, 6);
+        assert_eq!(solve(&[ratios(&[2, 4])], &ratios(&[5])).integer(), None);
+        assert_eq!(solve(&[ratios(&[2, 4, -6])], &ratios(&[3])).integer(), None);
+
+        // 3x + 5y == 1 only has integer solutions with fractional particular
+        // values along the way.
+        let solutions = solve(&[ratios(&[3, 5])], &ratios(&[1]));
+        let [x, y] = solutions.integer().unwrap()[..] else {
+            panic!("two unknowns");
+        };
+        assert_eq!(3 * x + 5 * y, 1);
+
+        let matrix = [ratios(&[1, 1]), ratios(&[2, 2])];
+        assert_eq!(solve(&matrix, &ratios(&[1, 3])), Solutions::Inconsistent);
+    }
+}
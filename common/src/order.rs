@@ -0,0 +1,175 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt,
+    hash::Hash,
+};
+
+/// Rules of the form "`a` comes before `b`", which need not relate every pair
+/// of values, nor be consistent.
+#[derive(Debug, Clone)]
+pub struct PartialOrder<T> {
+    after: HashMap<T, HashSet<T>>,
+    before: HashMap<T, HashSet<T>>,
+}
+
+/// Values that the rules order in a circle: each comes before the next, and
+/// the last before the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<T>(pub Vec<T>);
+
+impl<T: fmt::Display> fmt::Display for Cycle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the ordering rules contain a cycle: ")?;
+        for value in &self.0 {
+            write!(f, "{value} -> ")?;
+        }
+        match self.0.first() {
+            Some(first) => write!(f, "{first}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> Error for Cycle<T> {}
+
+impl<T: Copy + Eq + Hash> PartialOrder<T> {
+    pub fn new(rules: impl IntoIterator<Item = (T, T)>) -> Self {
+        let mut after: HashMap<T, HashSet<T>> = HashMap::new();
+        let mut before: HashMap<T, HashSet<T>> = HashMap::new();
+        for (a, b) in rules {
+            after.entry(a).or_default().insert(b);
+            before.entry(b).or_default().insert(a);
+        }
+        Self { after, before }
+    }
+
+    /// Whether a rule puts `a` before `b`.
+    pub fn precedes(&self, a: T, b: T) -> bool {
+        self.after.get(&a).is_some_and(|after| after.contains(&b))
+    }
+
+    /// The positions `(i, j)` of the first pair with `i < j` that a rule wants
+    /// the other way around, with `j` as small as possible.
+    pub fn violation(&self, sequence: &[T]) -> Option<(usize, usize)> {
+        let mut positions = HashMap::new();
+        for (j, &value) in sequence.iter().enumerate() {
+            let earlier = self
+                .after
+                .get(&value)
+                .into_iter()
+                .flatten()
+                .filter_map(|later| positions.get(later).copied())
+                .min();
+            if let Some(i) = earlier {
+                return Some((i, j));
+            }
+            positions.insert(value, j);
+        }
+        None
+    }
+
+    pub fn is_ordered(&self, sequence: &[T]) -> bool {
+        self.violation(sequence).is_none()
+    }
+
+    /// Order values so that no rule between two of them is broken, keeping
+    /// values that are free to go anywhere in their original order. Repeated
+    /// values are kept once, and rules involving other values are ignored.
+    pub fn sort(&self, values: &[T]) -> Result<Vec<T>, Cycle<T>> {
+        let mut subset = HashSet::new();
+        let values: Vec<T> = values
+            .iter()
+            .copied()
+            .filter(|&v| subset.insert(v))
+            .collect();
+        let positions: HashMap<T, usize> =
+            values.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+        // In the order given, so that the result does not depend on hashing.
+        let related = |map: &HashMap<T, HashSet<T>>, value: &T| -> Vec<T> {
+            let mut related: Vec<T> = map
+                .get(value)
+                .into_iter()
+                .flatten()
+                .filter(|v| subset.contains(v))
+                .copied()
+                .collect();
+            related.sort_unstable_by_key(|v| positions[v]);
+            related
+        };
+
+        let mut waiting: HashMap<T, usize> = values
+            .iter()
+            .map(|v| (*v, related(&self.before, v).len()))
+            .collect();
+        let mut ready: VecDeque<T> = values.iter().filter(|v| waiting[v] == 0).copied().collect();
+        let mut result = Vec::with_capacity(values.len());
+        while let Some(value) = ready.pop_front() {
+            result.push(value);
+            for next in related(&self.after, &value) {
+                let count = waiting.get_mut(&next).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+        if result.len() == values.len() {
+            return Ok(result);
+        }
+
+        // Every value left over still waits for another left over value, so
+        // walking back through them has to come round in a circle.
+        let placed: HashSet<T> = result.into_iter().collect();
+        let mut walk = vec![*values.iter().find(|v| !placed.contains(v)).unwrap()];
+        loop {
+            let current = walk[walk.len() - 1];
+            let previous = related(&self.before, &current)
+                .into_iter()
+                .find(|v| !placed.contains(v))
+                .unwrap();
+            if let Some(start) = walk.iter().position(|&v| v == previous) {
+                let mut cycle = walk.split_off(start);
+                cycle.reverse();
+                return Err(Cycle(cycle));
+            }
+            walk.push(previous);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_violation() {
+        let order = PartialOrder::new([(1, 2), (2, 3), (1, 4)]);
+        assert!(order.precedes(1, 2));
+        assert!(!order.precedes(2, 1));
+        assert!(!order.precedes(1, 3));
+
+        assert!(order.is_ordered(&[1, 2, 3]));
+        assert!(order.is_ordered(&[4, 3, 5]));
+        assert_eq!(order.violation(&[3, 4, 2, 1]), Some((0, 2)));
+        assert_eq!(order.violation(&[4, 2, 1]), Some((0, 2)));
+    }
+
+    #[test]
+    fn test_sort() {
+        let order = PartialOrder::new([(1, 2), (2, 3), (1, 4), (3, 1)]);
+        assert_eq!(order.sort(&[4, 5, 1]), Ok(vec![5, 1, 4]));
+        assert_eq!(order.sort(&[3, 2]), Ok(vec![2, 3]));
+        assert_eq!(order.sort(&[1, 2, 1, 4]), Ok(vec![1, 2, 4]));
+
+        let cycle = order.sort(&[4, 3, 2, 1]).unwrap_err();
+        assert_eq!(cycle.0.len(), 3);
+        for (i, &value) in cycle.0.iter().enumerate() {
+            assert!(order.precedes(value, cycle.0[(i + 1) % 3]));
+        }
+        assert_eq!(
+            Cycle(vec![1, 2, 3]).to_string(),
+            "the ordering rules contain a cycle: 1 -> 2 -> 3 -> 1"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use anyhow::{anyhow, Result};
+use std::{collections::HashMap, hash::Hash};
+
+/// How many equal intervals in a row make a signal count as periodic.
+const MIN_REPEATS: usize = 2;
+
+/// Something that happens at the times `offset + k * period` for every
+/// `k >= 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Periodic {
+    pub offset: u64,
+    pub period: u64,
+}
+
+/// `(gcd, x, y)` with `a * x + b * y == gcd`, the greatest common divisor of
+/// `a` and `b`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (gcd, x, y) = extended_gcd(b, a % b);
+        (gcd, y, x - (a / b) * y)
+    }
+}
+
+impl Periodic {
+    /// The times at which both happen, by the Chinese remainder theorem
+    /// generalized to periods that are not coprime. Fails if they never
+    /// happen at the same time.
+    pub fn align(self, other: Self) -> Result<Self> {
+        if self.period == 0 || other.period == 0 {
+            return Err(anyhow!("periods must be positive"));
+        }
+        let (o1, p1) = (i128::from(self.offset), i128::from(self.period));
+        let (o2, p2) = (i128::from(other.offset), i128::from(other.period));
+
+        let (g, x, _) = extended_gcd(p1, p2);
+        let difference = o2 - o1;
+        if difference % g != 0 {
+            return Err(anyhow!(
+                "{self:?} and {other:?} never happen at the same time"
+            ));
+        }
+
+        // The products below can reach 2^128, so they are taken as u128 with
+        // every factor reduced first.
+        let overflow = || anyhow!("aligning {self:?} and {other:?} overflows");
+        let modulus = p2 / g;
+        let reduce = |n: i128| n.rem_euclid(modulus).unsigned_abs();
+        let steps = reduce(difference / g) * reduce(x) % modulus.unsigned_abs();
+        let period = (p1 / g).unsigned_abs() * p2.unsigned_abs();
+        let mut offset = u128::from(self.offset)
+            .checked_add(p1.unsigned_abs() * steps)
+            .ok_or_else(overflow)?
+            % period;
+        // The congruences hold from the later offset on.
+        let start = u128::from(self.offset.max(other.offset));
+        if offset < start {
+            offset = (start - offset)
+                .div_ceil(period)
+                .checked_mul(period)
+                .and_then(|lift| lift.checked_add(offset))
+                .ok_or_else(overflow)?;
+        }
+
+        Ok(Self {
+            offset: u64::try_from(offset).map_err(|_| overflow())?,
+            period: u64::try_from(period).map_err(|_| overflow())?,
+        })
+    }
+
+    /// The times at which all of `signals` happen.
+    pub fn align_all(signals: &[Self]) -> Result<Self> {
+        let (&first, rest) = signals
+            .split_first()
+            .ok_or(anyhow!("no signals to align"))?;
+        rest.iter()
+            .try_fold(first, |acc, &signal| acc.align(signal))
+    }
+}
+
+/// The times at which signals fired, recorded as a simulation runs.
+#[derive(Debug, Clone)]
+pub struct EventLog<K> {
+    events: HashMap<K, Vec<u64>>,
+}
+
+impl<K: Eq + Hash> Default for EventLog<K> {
+    fn default() -> Self {
+        Self {
+            events: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> EventLog<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `signal` fired at `time`, which must not be earlier than
+    /// its last event. Firing again at the same time is ignored.
+    pub fn record(&mut self, signal: K, time: u64) {
+        let times = self.events.entry(signal).or_default();
+        if times.last() != Some(&time) {
+            times.push(time);
+        }
+    }
+
+    /// The offset and period of a signal, once its last events are evenly
+    /// spaced. The offset is the first event of that even run.
+    pub fn periodic(&self, signal: &K) -> Option<Periodic> {
+        let times = self.events.get(signal)?;
+        let intervals: Vec<u64> = times.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let &period = intervals.last()?;
+        let repeats = intervals
+            .iter()
+            .rev()
+            .take_while(|&&interval| interval == period)
+            .count();
+        (repeats >= MIN_REPEATS).then(|| Periodic {
+            offset: times[times.len() - 1 - repeats],
+            period,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn periodic(offset: u64, period: u64) -> Periodic {
+        Periodic { offset, period }
+    }
+
+    #[test]
+    fn test_align() {
+        let aligned = |a, b| Periodic::align(a, b).ok();
+        assert_eq!(
+            aligned(periodic(2, 4), periodic(4, 6)),
+            Some(periodic(10, 12))
+        );
+        assert_eq!(
+            aligned(periodic(14, 4), periodic(4, 6)),
+            Some(periodic(22, 12))
+        );
+        assert_eq!(aligned(periodic(1, 4), periodic(4, 6)), None);
+
+        let counters = [periodic(3, 3), periodic(5, 5), periodic(7, 7)];
+        assert_eq!(
+            Periodic::align_all(&counters).ok(),
+            Some(periodic(105, 105))
+        );
+        assert!(Periodic::align_all(&[]).is_err());
+
+        // Large coprime periods, whose combined period fits or does not.
+        let (p1, p2) = ((1 << 32) - 5, (1 << 31) - 1);
+        let aligned = Periodic::align(periodic(7, p1), periodic(123_456_789, p2)).unwrap();
+        assert_eq!(aligned.period, p1 * p2);
+        assert_eq!(aligned.offset % p1, 7);
+        assert_eq!(aligned.offset % p2, 123_456_789);
+        assert!(Periodic::align(periodic(3, u64::MAX), periodic(5, u64::MAX - 1)).is_err());
+    }
+
+    #[test]
+    fn test_event_log() {
+        let mut log = EventLog::new();
+        for time in [3, 7, 12, 12] {
+            log.record("a", time);
+        }
+        assert_eq!(log.periodic(&"a"), None);
+        log.record("a", 17);
+        assert_eq!(log.periodic(&"a"), Some(periodic(7, 5)));
+        log.record("a", 22);
+        assert_eq!(log.periodic(&"a"), Some(periodic(7, 5)));
+        assert_eq!(log.periodic(&"b"), None);
+    }
+}
@@ -0,0 +1,595 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Edge costs: non-negative, with `Default` as zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// A graph that is explored one state at a time instead of being built up
+/// front.
+pub trait StateSpace {
+    type State: Clone + Eq + Hash;
+    type Cost: Cost;
+
+    /// The states reachable in one step, with the cost of getting there.
+    fn successors(&self, state: &Self::State) -> impl Iterator<Item = (Self::State, Self::Cost)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A lower bound on the cost from `state` to the nearest goal, used by
+    /// `astar`. It must never overestimate, or the path found may not be the
+    /// cheapest. The default of zero turns A* into Dijkstra.
+    fn heuristic(&self, _state: &Self::State) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+/// A cheapest path, from a start state to a goal state inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Numbers the states in the order they are discovered, so the search can
+/// keep its bookkeeping in vectors.
+struct Interner<S> {
+    indices: HashMap<S, usize>,
+    states: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash> Interner<S> {
+    fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            states: Vec::new(),
+        }
+    }
+
+    /// The index of `state`, and whether it was seen for the first time.
+    fn intern(&mut self, state: S) -> (usize, bool) {
+        match self.indices.entry(state) {
+            Entry::Occupied(entry) => (*entry.get(), false),
+            Entry::Vacant(entry) => {
+                let index = self.states.len();
+                self.states.push(entry.key().clone());
+                entry.insert(index);
+                (index, true)
+            }
+        }
+    }
+}
+
+fn search<S: StateSpace>(
+    space: &S,
+    starts: impl IntoIterator<Item = S::State>,
+    heuristic: impl Fn(&S::State) -> S::Cost,
+) -> Option<Path<S::State, S::Cost>> {
+    let mut interner = Interner::new();
+    let mut costs: Vec<S::Cost> = Vec::new();
+    let mut parents: Vec<Option<usize>> = Vec::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        let (index, new) = interner.intern(start);
+        if new {
+            costs.push(S::Cost::default());
+            parents.push(None);
+            let estimate = heuristic(&interner.states[index]);
+            queue.push(Reverse((estimate, S::Cost::default(), index)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+        let state = interner.states[index].clone();
+        if space.is_goal(&state) {
+            let mut states = vec![state];
+            let mut current = index;
+            while let Some(parent) = parents[current] {
+                states.push(interner.states[parent].clone());
+                current = parent;
+            }
+            states.reverse();
+            return Some(Path { cost, states });
+        }
+
+        for (next, step) in space.successors(&state) {
+            let next_cost = cost + step;
+            let (next_index, new) = interner.intern(next);
+            if new {
+                costs.push(next_cost);
+                parents.push(Some(index));
+            } else if next_cost < costs[next_index] {
+                costs[next_index] = next_cost;
+                parents[next_index] = Some(index);
+            } else {
+                continue;
+            }
+            let estimate = next_cost + heuristic(&interner.states[next_index]);
+            queue.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+/// The cheapest path from any of `starts` to a goal, or `None` if no goal is
+/// reachable.
+pub fn dijkstra<S: StateSpace>(
+    space: &S,
+    starts: impl IntoIterator<Item = S::State>,
+) -> Option<Path<S::State, S::Cost>> {
+    search(space, starts, |_| S::Cost::default())
+}
+
+/// Like `dijkstra`, but guided by the space's heuristic so that fewer states
+/// are explored.
+pub fn astar<S: StateSpace>(
+    space: &S,
+    starts: impl IntoIterator<Item = S::State>,
+) -> Option<Path<S::State, S::Cost>> {
+    search(space, starts, |state| space.heuristic(state))
+}
+
+/// A state space whose states can be numbered `0..state_count()` up front,
+/// and whose steps have small integer costs. `dial` searches these with flat
+/// arrays and a bucket queue instead of hashing states and keeping a heap.
+pub trait DenseStateSpace: StateSpace {
+    fn state_count(&self) -> usize;
+
+    /// The number of `state`, below `state_count()`.
+    fn index(&self, state: &Self::State) -> usize;
+
+    /// An upper bound on the cost of a single step.
+    fn max_step(&self) -> Self::Cost;
+}
+
+/// Like `dijkstra`, using Dial's algorithm: the queue is a ring of
+/// `max_step() + 1` buckets, where each bucket holds the states whose cost
+/// is congruent to its index.
+pub fn dial<S>(
+    space: &S,
+    starts: impl IntoIterator<Item = S::State>,
+) -> Option<Path<S::State, S::Cost>>
+where
+    S: DenseStateSpace,
+    S::Cost: Into<u64>,
+{
+    let len = space.state_count();
+    let mut states: Vec<Option<S::State>> = vec![None; len];
+    let mut costs: Vec<Option<S::Cost>> = vec![None; len];
+    let mut parents: Vec<Option<usize>> = vec![None; len];
+    let mut done = vec![false; len];
+
+    let width = space.max_step().into() + 1;
+    let bucket = |cost: S::Cost| usize::try_from(cost.into() % width).unwrap();
+    let mut buckets = vec![Vec::new(); usize::try_from(width).unwrap()];
+    let mut queued = 0;
+
+    for start in starts {
+        let index = space.index(&start);
+        if costs[index].is_none() {
+            states[index] = Some(start);
+            costs[index] = Some(S::Cost::default());
+            buckets[0].push(index);
+            queued += 1;
+        }
+    }
+
+    // Every queued cost is within `max_step` of the cheapest one, so the
+    // buckets are visited in order of cost by going round the ring.
+    let mut current = 0;
+    while queued > 0 {
+        let Some(index) = buckets[current].pop() else {
+            current = (current + 1) % buckets.len();
+            continue;
+        };
+        queued -= 1;
+        let cost = costs[index].expect("queued states have a cost");
+        // A state is queued again whenever its cost improves; only the entry
+        // in the bucket of its final cost counts.
+        if done[index] || bucket(cost) != current {
+            continue;
+        }
+        done[index] = true;
+
+        let state = states[index]
+            .clone()
+            .expect("queued states are stored at their index");
+        if space.is_goal(&state) {
+            let mut path = vec![state];
+            let mut at = index;
+            while let Some(parent) = parents[at] {
+                path.push(
+                    states[parent]
+                        .clone()
+                        .expect("parents are stored at their index"),
+                );
+                at = parent;
+            }
+            path.reverse();
+            return Some(Path { cost, states: path });
+        }
+
+        for (next, step) in space.successors(&state) {
+            let next_cost = cost + step;
+            let next_index = space.index(&next);
+            if done[next_index] || costs[next_index].is_some_and(|c| c <= next_cost) {
+                continue;
+            }
+            states[next_index] = Some(next);
+            costs[next_index] = Some(next_cost);
+            parents[next_index] = Some(index);
+            buckets[bucket(next_cost)].push(next_index);
+            queued += 1;
+        }
+    }
+
+    None
+}
+
+/// Every cheapest path from the starts to the goals, kept as a DAG of the
+/// states on them and the predecessors of each state.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S, C> {
+    pub cost: C,
+    states: Vec<S>,
+    predecessors: Vec<Vec<usize>>,
+    is_start: Vec<bool>,
+    goals: Vec<usize>,
+    /// Indices of the states on at least one cheapest path, each after all
+    /// of its predecessors.
+    order: Vec<usize>,
+}
+
+impl<S, C> ShortestPaths<S, C> {
+    /// The states on at least one cheapest path.
+    pub fn states(&self) -> impl Iterator<Item = &S> {
+        self.order.iter().map(|&i| &self.states[i])
+    }
+
+    /// The distinct values of `f` over the states on cheapest paths, such as
+    /// the tiles they cover.
+    pub fn project<T: Eq + Hash>(&self, f: impl Fn(&S) -> T) -> HashSet<T> {
+        self.states().map(f).collect()
+    }
+
+    /// How many cheapest paths there are, counted without walking them.
+    pub fn count(&self) -> u128 {
+        let mut counts = vec![0; self.states.len()];
+        for &i in &self.order {
+            counts[i] = u128::from(self.is_start[i])
+                + self.predecessors[i]
+                    .iter()
+                    .map(|&p| counts[p])
+                    .sum::<u128>();
+        }
+        self.goals.iter().map(|&g| counts[g]).sum()
+    }
+
+    /// Every cheapest path, one at a time. There may be exponentially many.
+    pub fn paths(&self) -> impl Iterator<Item = Vec<&S>> {
+        let mut goals = self.goals.iter();
+        // A depth first walk back from a goal. Each frame holds a state and
+        // the next of its predecessors to visit, where 0 means the state
+        // itself has not been considered as the start of a path yet.
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        std::iter::from_fn(move || loop {
+            let Some(frame) = stack.last_mut() else {
+                stack.push((*goals.next()?, 0));
+                continue;
+            };
+            let (index, next) = *frame;
+            frame.1 += 1;
+            if next == 0 {
+                if self.is_start[index] {
+                    return Some(stack.iter().rev().map(|&(i, _)| &self.states[i]).collect());
+                }
+            } else if let Some(&predecessor) = self.predecessors[index].get(next - 1) {
+                stack.push((predecessor, 0));
+            } else {
+                stack.pop();
+            }
+        })
+    }
+}
+
+/// Like `dijkstra`, but keeps every cheapest path to every goal that can be
+/// reached at the lowest cost. Steps may cost zero, but panics if a cheapest
+/// path can go round a cycle of them, as there would be endlessly many.
+pub fn dijkstra_all<S: StateSpace>(
+    space: &S,
+    starts: impl IntoIterator<Item = S::State>,
+) -> Option<ShortestPaths<S::State, S::Cost>> {
+    let mut interner = Interner::new();
+    let mut costs: Vec<S::Cost> = Vec::new();
+    let mut predecessors: Vec<Vec<usize>> = Vec::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        let (index, new) = interner.intern(start);
+        if new {
+            costs.push(S::Cost::default());
+            predecessors.push(Vec::new());
+            queue.push(Reverse((S::Cost::default(), index)));
+        }
+    }
+    let mut is_start = vec![true; interner.states.len()];
+
+    let mut best = None;
+    let mut goals = Vec::new();
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        if cost > costs[index] {
+            continue;
+        }
+        let state = interner.states[index].clone();
+        if space.is_goal(&state) {
+            best = Some(cost);
+            goals.push(index);
+        }
+
+        for (next, step) in space.successors(&state) {
+            let next_cost = cost + step;
+            let (next_index, new) = interner.intern(next);
+            if new {
+                costs.push(next_cost);
+                predecessors.push(vec![index]);
+                is_start.push(false);
+            } else if next_cost < costs[next_index] {
+                costs[next_index] = next_cost;
+                predecessors[next_index] = vec![index];
+            } else {
+                if next_cost == costs[next_index] && !predecessors[next_index].contains(&index) {
+                    predecessors[next_index].push(index);
+                }
+                continue;
+            }
+            queue.push(Reverse((next_cost, next_index)));
+        }
+    }
+
+    let order = topological_order(&predecessors, &goals);
+    Some(ShortestPaths {
+        cost: best?,
+        states: interner.states,
+        predecessors,
+        is_start,
+        goals,
+        order,
+    })
+}
+
+/// The states from which a goal can be reached through `predecessors`, each
+/// after all of its own predecessors. States on a cycle of predecessors never
+/// come free, so there must not be one.
+fn topological_order(predecessors: &[Vec<usize>], goals: &[usize]) -> Vec<usize> {
+    let mut on_path = vec![false; predecessors.len()];
+    let mut stack = goals.to_vec();
+    while let Some(index) = stack.pop() {
+        if !std::mem::replace(&mut on_path[index], true) {
+            stack.extend(&predecessors[index]);
+        }
+    }
+
+    let mut successors = vec![Vec::new(); predecessors.len()];
+    let mut waiting = vec![0; predecessors.len()];
+    for index in (0..predecessors.len()).filter(|&i| on_path[i]) {
+        waiting[index] = predecessors[index].len();
+        for &p in &predecessors[index] {
+            successors[p].push(index);
+        }
+    }
+
+    let mut result: Vec<usize> = (0..predecessors.len())
+        .filter(|&i| on_path[i] && waiting[i] == 0)
+        .collect();
+    let mut next = 0;
+    while let Some(&index) = result.get(next) {
+        next += 1;
+        for &s in &successors[index] {
+            waiting[s] -= 1;
+            if waiting[s] == 0 {
+                result.push(s);
+            }
+        }
+    }
+    assert_eq!(
+        result.len(),
+        on_path.iter().filter(|&&on| on).count(),
+        "cheapest paths go round a cycle of zero cost steps"
+    );
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::Coord, grid::Grid};
+
+    /// Weighted edges between numbered nodes.
+    struct Graph {
+        edges: Vec<(u32, u32, u32)>,
+        goal: u32,
+    }
+
+    impl StateSpace for Graph {
+        type State = u32;
+        type Cost = u32;
+
+        fn successors(&self, state: &u32) -> impl Iterator<Item = (u32, u32)> {
+            let state = *state;
+            self.edges
+                .iter()
+                .filter(move |&&(from, _, _)| from == state)
+                .map(|&(_, to, cost)| (to, cost))
+        }
+
+        fn is_goal(&self, state: &u32) -> bool {
+            *state == self.goal
+        }
+    }
+
+    impl DenseStateSpace for Graph {
+        fn state_count(&self) -> usize {
+            self.edges
+                .iter()
+                .map(|&(from, to, _)| from.max(to))
+                .max()
+                .unwrap() as usize
+                + 1
+        }
+
+        fn index(&self, state: &u32) -> usize {
+            *state as usize
+        }
+
+        fn max_step(&self) -> u32 {
+            self.edges.iter().map(|&(_, _, cost)| cost).max().unwrap()
+        }
+    }
+
+    /// Walk from the top left to the bottom right corner, avoiding walls.
+    struct Maze(Grid<bool>);
+
+    impl StateSpace for Maze {
+        type State = Coord;
+        type Cost = usize;
+
+        fn successors(&self, state: &Coord) -> impl Iterator<Item = (Coord, usize)> {
+            self.0
+                .neighbours4(*state)
+                .filter(|&c| !self.0[c])
+                .map(|c| (c, 1))
+        }
+
+        fn is_goal(&self, state: &Coord) -> bool {
+            *state == Coord::new(self.0.width() - 1, self.0.height() - 1)
+        }
+
+        fn heuristic(&self, state: &Coord) -> usize {
+            state.manhattan(Coord::new(self.0.width() - 1, self.0.height() - 1))
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph = Graph {
+            edges: vec![(0, 1, 7), (0, 2, 2), (2, 1, 3), (1, 3, 1), (2, 3, 9)],
+            goal: 3,
+        };
+        let path = dijkstra(&graph, [0]).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states, [0, 2, 1, 3]);
+
+        assert_eq!(dijkstra(&graph, [3]).unwrap().states, [3]);
+        assert_eq!(dijkstra(&Graph { goal: 4, ..graph }, [0]), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let maze = Maze(Grid::parse("..#.\n#...\n..#.\n", |c| Ok(c == '#')).unwrap());
+        let path = astar(&maze, [Coord::new(0, 0)]).unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states.len(), 6);
+        assert_eq!(Some(path), dijkstra(&maze, [Coord::new(0, 0)]));
+    }
+
+    #[test]
+    fn test_dial() {
+        let graph = Graph {
+            edges: vec![(0, 1, 7), (0, 2, 2), (2, 1, 3), (1, 3, 1), (2, 3, 9)],
+            goal: 3,
+        };
+        assert_eq!(dial(&graph, [0]), dijkstra(&graph, [0]));
+        assert_eq!(dial(&graph, [2, 0]).unwrap().states, [2, 1, 3]);
+        assert_eq!(dial(&Graph { goal: 4, ..graph }, [0]), None);
+
+        // Long chains of cheap steps wrap round the ring of buckets many
+        // times before a more expensive shortcut is taken.
+        let mut edges: Vec<(u32, u32, u32)> = (0..20).map(|i| (i, i + 1, 1)).collect();
+        edges.extend([(0, 21, 9), (21, 20, 9), (0, 22, 12), (22, 20, 2)]);
+        let graph = Graph { edges, goal: 20 };
+        let path = dial(&graph, [0]).unwrap();
+        assert_eq!(path.cost, 14);
+        assert_eq!(path.states, [0, 22, 20]);
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        // Two routes of cost 4 from 0 to 3, one of cost 5, and a detour
+        // through 4 that ties with the direct edge into 2.
+        let graph = Graph {
+            edges: vec![
+                (0, 1, 2),
+                (0, 2, 2),
+                (1, 3, 2),
+                (2, 3, 2),
+                (0, 3, 5),
+                (0, 4, 1),
+                (4, 2, 1),
+                (3, 5, 1),
+            ],
+            goal: 3,
+        };
+        let paths = dijkstra_all(&graph, [0]).unwrap();
+        assert_eq!(paths.cost, 4);
+        assert_eq!(paths.count(), 3);
+        let mut all: Vec<Vec<u32>> = paths
+            .paths()
+            .map(|p| p.into_iter().copied().collect())
+            .collect();
+        all.sort();
+        assert_eq!(all, [vec![0, 1, 3], vec![0, 2, 3], vec![0, 4, 2, 3]]);
+        assert_eq!(paths.project(|&s| s), HashSet::from([0, 1, 2, 3, 4]));
+
+        assert!(dijkstra_all(&Graph { goal: 6, ..graph }, [0]).is_none());
+    }
+
+    #[test]
+    fn test_dijkstra_all_zero_cost() {
+        // A free step from 1 to 2 makes both routes through them cheapest.
+        let graph = Graph {
+            edges: vec![(0, 1, 1), (1, 2, 0), (0, 2, 1), (2, 3, 1)],
+            goal: 3,
+        };
+        let paths = dijkstra_all(&graph, [0]).unwrap();
+        assert_eq!(paths.cost, 2);
+        assert_eq!(paths.count(), 2);
+        assert_eq!(paths.paths().count(), 2);
+        assert_eq!(paths.states().count(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "cycle of zero cost steps")]
+    fn test_dijkstra_all_zero_cost_cycle() {
+        // 1 and 2 lead to each other for free, so each is the other's
+        // predecessor.
+        let graph = Graph {
+            edges: vec![(0, 1, 1), (0, 2, 1), (1, 2, 0), (2, 1, 0), (2, 3, 1)],
+            goal: 3,
+        };
+        dijkstra_all(&graph, [0]);
+    }
+
+    #[test]
+    fn test_dijkstra_all_grid() {
+        // Every monotone route across an open 3x3 grid is a cheapest path.
+        let maze = Maze(Grid::new(3, 3, false));
+        let paths = dijkstra_all(&maze, [Coord::new(0, 0)]).unwrap();
+        assert_eq!(paths.cost, 4);
+        assert_eq!(paths.count(), 6);
+        assert_eq!(paths.paths().count(), 6);
+        assert_eq!(paths.project(|c| c.x + c.y).len(), 5);
+    }
+}
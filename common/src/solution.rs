@@ -0,0 +1,221 @@
+use crate::dot::Dot;
+use anyhow::{anyhow, Error, Result};
+use std::{any::Any, fmt, str::FromStr};
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Self::A, Self::B];
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "a" | "A" | "1" => Ok(Self::A),
+            "b" | "B" | "2" => Ok(Self::B),
+            _ => Err(anyhow!("invalid part '{s}', expected a or b")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::A => write!(f, "a"),
+            Self::B => write!(f, "b"),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsigned(n) => write!(f, "{n}"),
+            Self::Signed(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::$variant(<$target>::try_from(n).unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+impl_answer_from!(Signed, i128, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_owned())
+    }
+}
+
+/// A solution to both parts of a day. The input is parsed once and shared
+/// between the parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Err(anyhow!("part 2 is not solved"))
+    }
+
+    /// A Graphviz drawing of the input, for days whose input is a graph.
+    fn dot(_input: &Self::Input) -> Result<Dot> {
+        Err(anyhow!("this day cannot draw its input"))
+    }
+}
+
+/// Object-safe view of a [`Solution`], so that days with different input types
+/// can be stored in one registry.
+pub trait DynSolution {
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    /// Solve a part given an input returned by `parse_any`.
+    fn solve_any(&self, input: &dyn Any, part: Part) -> Result<Answer>;
+
+    fn solve(&self, input: &str, part: Part) -> Result<Answer> {
+        self.solve_any(self.parse_any(input)?.as_ref(), part)
+    }
+
+    /// Draw an input returned by `parse_any`.
+    fn dot_any(&self, input: &dyn Any) -> Result<Dot>;
+}
+
+impl<S: Solution> DynSolution for S
+where
+    S::Input: 'static,
+{
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve_any(&self, input: &dyn Any, part: Part) -> Result<Answer> {
+        let input = downcast::<S>(input)?;
+        match part {
+            Part::A => S::part1(input),
+            Part::B => S::part2(input),
+        }
+    }
+
+    fn dot_any(&self, input: &dyn Any) -> Result<Dot> {
+        S::dot(downcast::<S>(input)?)
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> Result<&S::Input>
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .ok_or_else(|| anyhow!("input was not parsed by this solution"))
+}
+
+/// All solutions of one year, along with where that year keeps its inputs.
+pub struct Year {
+    pub year: u32,
+    pub inputs_dir: &'static str,
+    pub days: &'static [(u32, &'static dyn DynSolution)],
+}
+
+impl Year {
+    pub fn day(&self, day: u32) -> Option<&'static dyn DynSolution> {
+        self.days
+            .iter()
+            .find(|&&(d, _)| d == day)
+            .map(|&(_, solution)| solution)
+    }
+}
+
+impl fmt::Debug for Year {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Year")
+            .field("year", &self.year)
+            .field("inputs_dir", &self.inputs_dir)
+            .field(
+                "days",
+                &self.days.iter().map(|&(d, _)| d).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input.lines().map(|l| Ok(l.parse()?)).collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer> {
+            Ok(input.iter().sum::<i64>().into())
+        }
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!("a".parse::<Part>().unwrap(), Part::A);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::B);
+        assert!("c".parse::<Part>().is_err());
+        assert_eq!(Part::B.to_string(), "b");
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(-3).to_string(), "-3");
+        assert_eq!(Answer::from("1,2,3").to_string(), "1,2,3");
+    }
+
+    #[test]
+    fn test_dyn_solution() {
+        let solution: &dyn DynSolution = &Sum;
+        assert_eq!(
+            solution.solve("1\n-4\n", Part::A).unwrap(),
+            Answer::Signed(-3)
+        );
+        assert!(solution.solve("1\n-4\n", Part::B).is_err());
+        assert!(solution.solve_any(&"not parsed", Part::A).is_err());
+        let input = solution.parse_any("1\n").unwrap();
+        assert!(solution.dot_any(input.as_ref()).is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{
+    geometry::{Coord, Direction},
+    grid::Grid,
+};
+
+/// Disjoint sets of the numbers `0..n`, merged by union by size with path
+/// compression.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// Every number in a set of its own.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parents: (0..n).collect(),
+            sizes: vec![1; n],
+            count: n,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The number of disjoint sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The representative of the set containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut current = x;
+        while current != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+
+    /// Merge the sets containing `a` and `b`, returning whether they were
+    /// disjoint.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (small, large) = if self.sizes[a] < self.sizes[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.count -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The size of the set containing `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.sizes[root]
+    }
+
+    /// The members of every set, each in increasing order, with the sets
+    /// ordered by their smallest members.
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut result: Vec<Vec<usize>> = Vec::with_capacity(self.count);
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index[root] == usize::MAX {
+                index[root] = result.len();
+                result.push(Vec::new());
+            }
+            result[index[root]].push(x);
+        }
+        result
+    }
+}
+
+/// Connected cells of a grid, joined through their edges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// The cells in reading order.
+    pub cells: Vec<Coord>,
+    /// The number of cell edges on the border of the region.
+    pub perimeter: usize,
+    /// The number of straight runs of border, which is also its number of
+    /// corners.
+    pub sides: usize,
+    /// The top left corner of the bounding box.
+    pub min: Coord,
+    /// The bottom right corner of the bounding box, inclusive.
+    pub max: Coord,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// Split a grid into regions of neighbouring cells for which `same` holds,
+/// ordered by their first cells in reading order.
+pub fn regions<T>(grid: &Grid<T>, same: impl Fn(&T, &T) -> bool) -> Vec<Region> {
+    let index = |c: Coord| c.y * grid.width() + c.x;
+    let mut sets = UnionFind::new(grid.width() * grid.height());
+    for (coord, value) in grid.iter() {
+        for d in [Direction::Right, Direction::Down] {
+            if let Some(neighbour) = grid.step(coord, d) {
+                if same(value, &grid[neighbour]) {
+                    sets.union(index(coord), index(neighbour));
+                }
+            }
+        }
+    }
+
+    let mut result: Vec<Region> = Vec::with_capacity(sets.count());
+    let mut labels = vec![usize::MAX; grid.width() * grid.height()];
+    let mut region_of = vec![usize::MAX; labels.len()];
+    for coord in grid.coords() {
+        let root = sets.find(index(coord));
+        if region_of[root] == usize::MAX {
+            region_of[root] = result.len();
+            result.push(Region {
+                cells: Vec::new(),
+                perimeter: 0,
+                sides: 0,
+                min: coord,
+                max: coord,
+            });
+        }
+        labels[index(coord)] = region_of[root];
+        let region = &mut result[region_of[root]];
+        region.cells.push(coord);
+        region.min.x = region.min.x.min(coord.x);
+        region.max.x = region.max.x.max(coord.x);
+        region.max.y = coord.y;
+    }
+
+    let label = |c: Option<Coord>| c.map(|c| labels[index(c)]);
+    for coord in grid.coords() {
+        let inside = Some(labels[index(coord)]);
+        let region = &mut result[labels[index(coord)]];
+        for d in Direction::ALL {
+            let ahead = grid.step(coord, d);
+            if label(ahead) != inside {
+                region.perimeter += 1;
+            }
+            // Count the corner between this direction and the next one round,
+            // either bending outwards or, if both sides belong to the region
+            // but the diagonal does not, inwards.
+            let side = grid.step(coord, d.clockwise());
+            let diagonal = ahead.and_then(|c| grid.step(c, d.clockwise()));
+            let (ahead, side) = (label(ahead) == inside, label(side) == inside);
+            if (!ahead && !side) || (ahead && side && label(diagonal) != inside) {
+                region.sides += 1;
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.same(0, 3));
+        assert!(!sets.same(0, 4));
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.count(), 3);
+        assert_eq!(sets.sets(), vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid = Grid::parse_chars("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+        let found = regions(&grid, |a, b| a == b);
+        let summary: Vec<_> = found
+            .iter()
+            .map(|r| (grid[r.cells[0]], r.area(), r.perimeter, r.sides))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+        assert_eq!(found[2].min, Coord::new(2, 1));
+        assert_eq!(found[2].max, Coord::new(3, 3));
+
+        // The sides of a region with holes include the sides around them.
+        let grid = Grid::parse_chars("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA").unwrap();
+        let found = regions(&grid, |a, b| a == b);
+        assert_eq!(found[0].sides, 12);
+        assert_eq!(found[0].area(), 28);
+    }
+}